@@ -0,0 +1,65 @@
+//! Statistical benchmarks of `parse` and both parts of every day, run against its `input.txt`.
+//!
+//! Save a baseline before a change and compare against it afterwards:
+//!
+//!     cargo bench -p aoc --bench days -- --save-baseline before
+//!     cargo bench -p aoc --bench days -- --baseline before
+//!
+//! A single day can be selected with criterion's filter, e.g. `cargo bench -p aoc --bench days -- day_3/`.
+
+use std::hint::black_box;
+use std::path::Path;
+
+use aoc_common::{Solution, read_input_file};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day_{}", S::DAY)).join("rust").join("input.txt");
+
+    let raw_input = match read_input_file(&path) {
+        Ok(Some(raw_input)) => raw_input,
+        Ok(None) => {
+            eprintln!("skipping day {}: no {} or it is empty", S::DAY, path.display());
+            return;
+        },
+        Err(error) => {
+            eprintln!("skipping day {}: {}", S::DAY, error);
+            return;
+        }
+    };
+
+    let input = match S::parse(&raw_input) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("skipping day {}: {}", S::DAY, error);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day_{}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw_input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+
+    group.finish();
+}
+
+
+fn days(c: &mut Criterion) {
+
+    bench_day::<day_1::Day1>(c);
+    bench_day::<day_2::Day2>(c);
+    bench_day::<day_3::Day3>(c);
+    bench_day::<day_4::Day4>(c);
+    bench_day::<day_5::Day5>(c);
+    bench_day::<day_6::Day6>(c);
+    bench_day::<day_7::Day7>(c);
+}
+
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,233 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+
+
+thread_local! {
+
+    // allocations made by this thread minus the ones it freed; frees of memory from other threads saturate at zero
+    static LIVE: Cell<usize> = const { Cell::new(0) };
+    static LIMIT: Cell<usize> = const { Cell::new(usize::MAX) };
+    static EXCEEDED: Cell<*const AtomicBool> = const { Cell::new(std::ptr::null()) };
+}
+
+#[cfg(feature = "alloc-stats")]
+thread_local! {
+
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+}
+
+
+/// The system allocator, keeping a per-thread count of live bytes so memory budgets can be enforced.
+///
+/// A thread that goes over its budget raises its flag and is put to sleep for good instead of allocating,
+/// since an allocator cannot unwind. With the `alloc-stats` feature it also counts allocations for [`measure`].
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+
+    /// Records an allocation of `size` bytes that adds `growth` live bytes, sleeping forever when that would go over
+    /// the budget.
+    #[cfg_attr(not(feature = "alloc-stats"), allow(unused_variables))]
+    fn track_alloc(growth: usize, size: usize) {
+
+        let live = LIVE.get().saturating_add(growth);
+
+        if live > LIMIT.get() {
+
+            let exceeded = EXCEEDED.get();
+            if !exceeded.is_null() {
+
+                // SAFETY: the flag outlives the thread, see `set_budget`
+                unsafe { (*exceeded).store(true, Ordering::SeqCst) };
+            }
+
+            loop {
+
+                std::thread::sleep(Duration::from_secs(3600));
+            }
+        }
+
+        LIVE.set(live);
+
+        #[cfg(feature = "alloc-stats")]
+        {
+            ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+            BYTES.set(BYTES.get() + size as u64);
+            PEAK.set(PEAK.get().max(live));
+        }
+    }
+
+    fn track_free(size: usize) {
+
+        LIVE.set(LIVE.get().saturating_sub(size));
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+
+        Self::track_alloc(layout.size(), layout.size());
+        return unsafe { System.alloc(layout) };
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+
+        Self::track_alloc(layout.size(), layout.size());
+        return unsafe { System.alloc_zeroed(layout) };
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+
+        Self::track_free(layout.size());
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+    // forwarded so growing a `Vec` can stay in place instead of the default alloc, copy and free
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+
+        match new_size.checked_sub(layout.size()) {
+            Some(growth) => Self::track_alloc(growth, new_size),
+            None => {
+                Self::track_free(layout.size() - new_size);
+                Self::track_alloc(0, new_size);
+            },
+        }
+
+        return unsafe { System.realloc(ptr, layout, new_size) };
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+
+/// Caps the live bytes of the current thread at `limit`, raising `exceeded` when an allocation would go over it.
+///
+/// `exceeded` must stay alive until `clear_budget`; a thread over its budget never gets that far.
+pub fn set_budget(limit: usize, exceeded: &AtomicBool) {
+
+    EXCEEDED.set(exceeded);
+    LIMIT.set(limit);
+}
+
+pub fn clear_budget() {
+
+    LIMIT.set(usize::MAX);
+    EXCEEDED.set(std::ptr::null());
+}
+
+
+/// Heap usage of one parse or part, counted on the thread that ran it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Most bytes live at once, above what the thread held before.
+    pub peak: usize
+}
+
+
+/// Runs `func` and counts what it allocates; without the `alloc-stats` feature nothing is counted.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (Option<AllocStats>, T) {
+
+    #[cfg(feature = "alloc-stats")]
+    {
+        let baseline = LIVE.get();
+        let (allocations, bytes) = (ALLOCATIONS.get(), BYTES.get());
+        PEAK.set(baseline);
+
+        let output = func();
+
+        let stats = AllocStats{
+            allocations: ALLOCATIONS.get() - allocations,
+            bytes: BYTES.get() - bytes,
+            peak: PEAK.get() - baseline
+        };
+
+        return (Some(stats), output);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    return (None, func());
+}
+
+
+pub fn format_bytes(bytes: usize) -> String {
+
+    return match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.2} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.2} MiB", bytes as f64 / 1_048_576.0),
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn measure_counts_allocations() {
+
+        let (stats, _) = measure(|| {
+            let first = vec![0u8; 4096];
+            drop(first);
+            vec![0u8; 1024]
+        });
+
+        assert_eq!(stats, Some(AllocStats{ allocations: 2, bytes: 5120, peak: 4096 }));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn measure_counts_reallocations_once() {
+
+        let (stats, _) = measure(|| {
+            let mut grown: Vec<u8> = Vec::with_capacity(1024);
+            grown.reserve_exact(4096);
+            grown
+        });
+
+        assert_eq!(stats, Some(AllocStats{ allocations: 2, bytes: 1024 + 4096, peak: 4096 }));
+    }
+
+    #[test]
+    fn realloc_tracks_live_bytes() {
+
+        let before = LIVE.get();
+        let mut buffer: Vec<u8> = Vec::with_capacity(16);
+
+        buffer.reserve_exact(4096);
+        assert_eq!(LIVE.get(), before + buffer.capacity());
+
+        buffer.shrink_to(8);
+        assert_eq!(LIVE.get(), before + buffer.capacity());
+
+        drop(buffer);
+        assert_eq!(LIVE.get(), before);
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn measure_counts_nothing_without_feature() {
+
+        assert_eq!(measure(|| vec![0u8; 16]).0, None);
+    }
+
+    #[test]
+    fn format_bytes_scales_units() {
+
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00 MiB");
+    }
+}
@@ -0,0 +1,170 @@
+use std::path::Path;
+
+use aoc_common::Part;
+
+
+
+/// Known answers of a day, stored next to its `input.txt` as `answers.toml`.
+///
+/// Only the flat subset of TOML the file needs is understood: `part1 = "..."` and `part2 = "..."`
+/// lines, blank lines and `#` comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+
+    pub part1: Option<String>,
+    pub part2: Option<String>
+}
+
+impl Answers {
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+
+        return match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        };
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+
+        let mut answers = Self::default();
+
+        for (idx, line) in content.lines().enumerate() {
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| format!("line {}: expected `key = \"value\"`", idx + 1))?;
+            let value = value.trim().strip_prefix('"').and_then(|value| value.strip_suffix('"'))
+                .ok_or_else(|| format!("line {}: value must be a quoted string", idx + 1))?;
+
+            match key.trim() {
+                "part1" => answers.part1 = Some(value.to_owned()),
+                "part2" => answers.part2 = Some(value.to_owned()),
+
+                other => return Err(format!("line {}: unknown key `{}`", idx + 1, other))
+            }
+        }
+
+        return Ok(answers);
+    }
+
+    pub fn to_toml(&self) -> String {
+
+        let mut output = String::new();
+
+        for part in Part::ALL {
+
+            if let Some(answer) = self.get(part) {
+
+                output += &format!("part{} = \"{}\"\n", part.number(), answer);
+            }
+        }
+
+        return output;
+    }
+
+    /// Returns `None` when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+
+        if !path.exists() {
+
+            return Ok(None);
+        }
+
+        let content = aoc_common::read_file(path)?;
+        return Ok(Some(Self::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))?));
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+
+        std::fs::write(path, self.to_toml())?;
+        return Ok(());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse_skips_comments_and_blank_lines() {
+
+        let answers = Answers::parse("# day 1\n\npart1 = \"1226\"\n  # recorded twice\n   part2=\"1252\"  \n\n").unwrap();
+        assert_eq!(answers, Answers{ part1: Some("1226".to_owned()), part2: Some("1252".to_owned()) });
+    }
+
+    #[test]
+    fn parse_allows_missing_parts() {
+
+        assert_eq!(Answers::parse("part2 = \"7\"\n").unwrap(), Answers{ part1: None, part2: Some("7".to_owned()) });
+        assert_eq!(Answers::parse("").unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn parse_rejects_unquoted_value() {
+
+        assert_eq!(Answers::parse("\npart1 = 1226\n").unwrap_err(), "line 2: value must be a quoted string");
+    }
+
+    #[test]
+    fn parse_rejects_unknown_key() {
+
+        assert_eq!(Answers::parse("part1 = \"1\"\npart3 = \"3\"\n").unwrap_err(), "line 2: unknown key `part3`");
+    }
+
+    #[test]
+    fn parse_rejects_line_without_key() {
+
+        assert_eq!(Answers::parse("1226\n").unwrap_err(), "line 1: expected `key = \"value\"`");
+    }
+
+    #[test]
+    fn to_toml_round_trips() {
+
+        let cases = [
+            Answers{ part1: Some("1226".to_owned()), part2: Some("1252".to_owned()) },
+            Answers{ part1: Some("4512".to_owned()), part2: None },
+            Answers{ part1: None, part2: Some("a = b".to_owned()) },
+            Answers::default(),
+        ];
+
+        for answers in cases {
+
+            assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+        }
+
+        assert_eq!(Answers{ part1: Some("1".to_owned()), part2: Some("2".to_owned()) }.to_toml(), "part1 = \"1\"\npart2 = \"2\"\n");
+    }
+
+    #[test]
+    fn load_missing_file_is_none() {
+
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(Answers::load(&dir.path().join("answers.toml")).unwrap(), None);
+    }
+
+    #[test]
+    fn save_then_load() {
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        let answers = Answers{ part1: Some("37".to_owned()), part2: Some("168".to_owned()) };
+
+        answers.save(&path).unwrap();
+        assert_eq!(Answers::load(&path).unwrap(), Some(answers));
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::time::{Duration, Instant};
+
+use crate::allocator::{self, format_bytes};
+
+
+
+/// Wall-clock and heap limits for parsing and for each part; `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+
+    pub timeout: Option<Duration>,
+    /// Peak bytes a single parse or part may have allocated at once.
+    pub memory: Option<usize>
+}
+
+
+/// Runs `func` within `limits`, on a worker thread when there are any.
+///
+/// A worker that runs out of time or memory is abandoned and the error says which budget it exceeded.
+pub fn run<T, F>(limits: &Limits, func: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, String> + Send + 'static
+{
+
+    if *limits == Limits::default() {
+
+        return func();
+    }
+
+    let exceeded = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = channel();
+
+    {
+        let exceeded = exceeded.clone();
+        let memory = limits.memory;
+
+        std::thread::spawn(move || {
+
+            allocator::set_budget(memory.unwrap_or(usize::MAX), &exceeded);
+            let result = func();
+            allocator::clear_budget();
+
+            let _ = sender.send(result);
+        });
+    }
+
+    let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+
+        if exceeded.load(Ordering::SeqCst) {
+
+            return Err(format!("exceeded the memory budget of {}", format_bytes(limits.memory.unwrap_or_default())));
+        }
+
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+
+            return Err(format!("timed out after {:.2?}", limits.timeout.unwrap_or_default()));
+        }
+
+        match receiver.recv_timeout(Duration::from_millis(5)) {
+            Ok(result) => return result,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => return Err("worker thread exited without a result".to_owned()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn no_limits_runs_inline() {
+
+        assert_eq!(run(&Limits::default(), || Ok(std::thread::current().id())), Ok(std::thread::current().id()));
+    }
+
+    #[test]
+    fn timeout_is_reported() {
+
+        let limits = Limits{ timeout: Some(Duration::from_millis(20)), memory: None };
+        let result = run(&limits, || {
+            std::thread::sleep(Duration::from_secs(5));
+            Ok(())
+        });
+
+        assert_eq!(result, Err("timed out after 20.00ms".to_owned()));
+    }
+
+    #[test]
+    fn memory_budget_is_reported() {
+
+        let limits = Limits{ timeout: None, memory: Some(1 << 20) };
+
+        assert_eq!(run(&limits, || Ok(vec![0u8; 1 << 19].len())), Ok(1 << 19));
+        assert_eq!(run(&limits, || Ok(vec![0u8; 1 << 21].len())), Err("exceeded the memory budget of 1.00 MiB".to_owned()));
+
+        let grown = run(&limits, || {
+            let mut buffer = vec![0u8; 1 << 19];
+            buffer.reserve_exact(1 << 20);
+            Ok(buffer.capacity())
+        });
+        assert_eq!(grown, Err("exceeded the memory budget of 1.00 MiB".to_owned()));
+    }
+}
@@ -0,0 +1,336 @@
+mod allocator;
+mod answers;
+mod budget;
+mod output;
+mod registry;
+mod scaffold;
+mod table;
+mod verify;
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::{InputCache, InputSource, MirrorFetcher, Part, Rng};
+use rayon::prelude::*;
+
+use budget::Limits;
+use output::Format;
+use registry::Report;
+
+
+
+const USAGE: &str = "usage:
+    aoc run <day>... [--part 1|2] [--input PATH|-] [--profile NAME]... [--all-profiles] [--format text|table|json|csv] [--table]
+    aoc run --all [--parallel] [--part 1|2] [--profile NAME]... [--all-profiles] [--format text|table|json|csv]
+
+    both also take [--timeout SECONDS] [--memory-limit MIB]
+    aoc new <day>
+    aoc gen <day> [--size N] [--seed S]
+    aoc verify [<day>...] [--record]
+
+--all runs every day that has an input, printing a summary table unless another format is asked for; --parallel
+spreads the days over all cores. A day that cannot be read or parsed, or panics, is reported as failed and makes aoc exit with 1.
+--timeout and --memory-limit apply to parsing and to each part separately; a day going over either is reported
+as failed. Built with `--features alloc-stats`, run also reports allocations and peak heap of each of them.
+--profile reads inputs/day_<day>/<NAME>.txt, copying it from $AOC_INPUT_MIRROR (default: mirror/) on first use;
+--all-profiles runs every input already in inputs/day_<day>. verify checks each day's input.txt against answers.toml
+and every inputs/day_<day>/<NAME>.txt against <NAME>.answers.toml. gen prints a random input of N records
+(default 1000) that the day parses; the same seed (default 0) always gives the same input.";
+
+
+struct RunArgs {
+
+    days: Vec<u8>,
+    all: bool,
+    parallel: bool,
+    parts: Vec<Part>,
+    input: Option<InputSource>,
+    profiles: Vec<String>,
+    all_profiles: bool,
+    limits: Limits,
+    format: Format
+}
+
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, Box<dyn std::error::Error>> {
+
+    let mut days = vec![];
+    let mut all = false;
+    let mut parallel = false;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = None;
+    let mut profiles = vec![];
+    let mut all_profiles = false;
+    let mut limits = Limits::default();
+    let mut format = None;
+
+    while let Some(arg) = args.next() {
+
+        match arg.as_str() {
+
+            "--part" => {
+                parts = match args.next().as_deref() {
+                    Some("1") => vec![Part::One],
+                    Some("2") => vec![Part::Two],
+                    other => return Err(format!("invalid part: {}", other.unwrap_or("<none>")).into())
+                };
+            },
+            "--input" => {
+                input = Some(InputSource::from_arg(&args.next().ok_or("missing value for --input")?));
+            },
+            "--profile" => {
+                profiles.push(args.next().ok_or("missing value for --profile")?);
+            },
+            "--all-profiles" => all_profiles = true,
+            "--timeout" => {
+                let arg = args.next().ok_or("missing value for --timeout")?;
+                let seconds = arg.parse::<f64>().ok().filter(|seconds| seconds.is_finite() && *seconds > 0.0).ok_or_else(|| format!("invalid timeout: {}", arg))?;
+                limits.timeout = Some(Duration::from_secs_f64(seconds));
+            },
+            "--memory-limit" => {
+                let arg = args.next().ok_or("missing value for --memory-limit")?;
+                let mebibytes = arg.parse::<usize>().ok().filter(|mebibytes| *mebibytes > 0).ok_or_else(|| format!("invalid memory limit: {}", arg))?;
+                limits.memory = Some(mebibytes.saturating_mul(1 << 20));
+            },
+            "--all" => all = true,
+            "--parallel" => parallel = true,
+            "--format" => {
+                let arg = args.next().ok_or("missing value for --format")?;
+                format = Some(Format::from_arg(&arg).ok_or_else(|| format!("invalid format: {}", arg))?);
+            },
+            "--table" => format = Some(Format::Table),
+
+            _ if arg.starts_with("--") => return Err(format!("unexpected argument: {}", arg).into()),
+            _ => days.push(parse_day(Some(arg))?)
+        }
+    }
+
+    if all && (!days.is_empty() || input.is_some()) {
+
+        return Err("--all cannot be combined with days or --input".into());
+    }
+
+    if days.is_empty() && !all {
+
+        return Err("missing <day>".into());
+    }
+
+    if input.is_some() && days.len() > 1 {
+
+        return Err("--input can only be used with a single day".into());
+    }
+
+    if input.is_some() && (all_profiles || !profiles.is_empty()) {
+
+        return Err("--input cannot be combined with profiles".into());
+    }
+
+    return Ok(RunArgs{
+        days,
+        all,
+        parallel,
+        parts,
+        input,
+        profiles,
+        all_profiles,
+        limits,
+        format: format.unwrap_or(if all { Format::Table } else { Format::Text })
+    });
+}
+
+
+fn parse_day(arg: Option<String>) -> Result<u8, Box<dyn std::error::Error>> {
+
+    let day = arg.ok_or("missing <day>")?;
+    return day.parse().map_err(|_| format!("invalid day: {}", day).into());
+}
+
+
+fn repo_root() -> PathBuf {
+
+    return Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc crate lives inside the repository").to_path_buf();
+}
+
+
+fn day_dir(day: u8) -> PathBuf {
+
+    return repo_root().join(format!("day_{}", day)).join("rust");
+}
+
+
+fn input_cache() -> InputCache<MirrorFetcher> {
+
+    let mirror = std::env::var_os("AOC_INPUT_MIRROR").map(PathBuf::from).unwrap_or_else(|| repo_root().join("mirror"));
+    return InputCache::new(repo_root().join("inputs"), MirrorFetcher::new(mirror));
+}
+
+
+/// One input of one day to solve.
+struct Job {
+
+    day: &'static registry::Day,
+    profile: Option<String>
+}
+
+
+fn jobs(args: &RunArgs) -> Result<Vec<Job>, Box<dyn std::error::Error>> {
+
+    let days = match args.all {
+        true => registry::DAYS.iter().collect(),
+        false => args.days.iter().map(|day| registry::find(*day).ok_or_else(|| format!("day {} is not implemented", day))).collect::<Result<Vec<_>, _>>()?,
+    };
+
+    let mut jobs = vec![];
+
+    for day in days {
+
+        let profiles = if args.all_profiles { input_cache().profiles(day.number)? } else { args.profiles.clone() };
+
+        if profiles.is_empty() {
+
+            // a day still waiting for its input is not a failure of the season
+            if args.all && matches!(aoc_common::read_input_file(day_dir(day.number).join("input.txt")), Ok(None)) {
+
+                eprintln!("day {} | skipped: no input.txt or it is empty", day.number);
+                continue;
+            }
+
+            jobs.push(Job{ day, profile: None });
+            continue;
+        }
+
+        jobs.extend(profiles.into_iter().map(|profile| Job{ day, profile: Some(profile) }));
+    }
+
+    return Ok(jobs);
+}
+
+
+/// Solves one job, turning unreadable and unparsable inputs into a failed report.
+fn run_job(job: &Job, args: &RunArgs) -> Report {
+
+    let raw_input = match &job.profile {
+        Some(profile) => input_cache().get(job.day.number, profile).map_err(|error| error.to_string()),
+        None => {
+            let source = args.input.clone().unwrap_or_else(|| InputSource::File(day_dir(job.day.number).join("input.txt")));
+            source.read().map_err(|error| error.to_string())
+        },
+    };
+
+    let mut report = match raw_input.and_then(|raw_input| (job.day.solve)(&raw_input, &args.parts, &args.limits).map_err(|error| error.to_string())) {
+        Ok(report) => report,
+        Err(error) => Report::failure(job.day.number, error),
+    };
+
+    report.profile = job.profile.clone();
+    return report;
+}
+
+
+fn run(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+
+    let jobs = jobs(&args)?;
+
+    let reports: Vec<Report> = match args.parallel {
+        true => jobs.par_iter().map(|job| run_job(job, &args)).collect(),
+        false => jobs.iter().map(|job| run_job(job, &args)).collect(),
+    };
+
+    print!("{}", output::render(args.format, &reports));
+
+    let failures = reports.iter().filter(|report| report.failed()).count();
+    if failures > 0 {
+
+        return Err(format!("{} of {} run(s) failed", failures, reports.len()).into());
+    }
+
+    return Ok(());
+}
+
+
+fn new(day: u8) -> Result<(), Box<dyn std::error::Error>> {
+
+    scaffold::create_day(&repo_root(), day)?;
+    println!("created day_{0}/rust, run it with `aoc run {0}`", day);
+
+    return Ok(());
+}
+
+
+fn generate(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn std::error::Error>> {
+
+    let day = parse_day(args.next())?;
+    let day = registry::find(day).ok_or_else(|| format!("day {} is not implemented", day))?;
+    let mut size = 1000;
+    let mut seed = 0;
+
+    while let Some(arg) = args.next() {
+
+        match arg.as_str() {
+
+            "--size" => {
+                let arg = args.next().ok_or("missing value for --size")?;
+                size = arg.parse().ok().filter(|size| *size > 0).ok_or_else(|| format!("invalid size: {}", arg))?;
+            },
+            "--seed" => {
+                let arg = args.next().ok_or("missing value for --seed")?;
+                seed = arg.parse().map_err(|_| format!("invalid seed: {}", arg))?;
+            },
+
+            _ => return Err(format!("unexpected argument: {}", arg).into())
+        }
+    }
+
+    print!("{}", (day.generate)(size, &mut Rng::new(seed)));
+
+    return Ok(());
+}
+
+
+fn verify(args: impl Iterator<Item = String>) -> Result<(), Box<dyn std::error::Error>> {
+
+    let mut days: Vec<&registry::Day> = vec![];
+    let mut record = false;
+
+    for arg in args {
+
+        match arg.as_str() {
+
+            "--record" => record = true,
+
+            _ => {
+                let day = parse_day(Some(arg))?;
+                days.push(registry::find(day).ok_or_else(|| format!("day {} is not implemented", day))?);
+            }
+        }
+    }
+
+    if days.is_empty() {
+
+        days = registry::DAYS.iter().collect();
+    }
+
+    return verify::verify(&days, record);
+}
+
+
+fn main() {
+
+    let mut args = std::env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+
+        Some("run") => parse_run_args(args).and_then(run),
+        Some("new") => parse_day(args.next()).and_then(new),
+        Some("gen") => generate(args),
+        Some("verify") => verify(args),
+
+        _ => Err(USAGE.into())
+    };
+
+    if let Err(error) = result {
+
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
+}
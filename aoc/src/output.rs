@@ -0,0 +1,363 @@
+use aoc_common::{Part, format_duration};
+
+use crate::allocator::{AllocStats, format_bytes};
+use crate::registry::{PartReport, Report};
+use crate::table;
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+
+    Text,
+    Table,
+    Json,
+    Csv
+}
+
+impl Format {
+
+    pub fn from_arg(arg: &str) -> Option<Self> {
+
+        return match arg {
+            "text" => Some(Format::Text),
+            "table" => Some(Format::Table),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        };
+    }
+}
+
+
+pub fn render(format: Format, reports: &[Report]) -> String {
+
+    return match format {
+        Format::Text => text(reports),
+        Format::Table => summary_table(reports),
+        Format::Json => json(reports),
+        Format::Csv => csv(reports),
+    };
+}
+
+
+/// ` (N allocations, X allocated, peak Y)` when allocations were counted.
+fn alloc_summary(stats: Option<AllocStats>) -> String {
+
+    return match stats {
+        Some(stats) => format!(" ({} allocations, {} allocated, peak {})", stats.allocations, format_bytes(stats.bytes as usize), format_bytes(stats.peak)),
+        None => String::new(),
+    };
+}
+
+
+fn has_alloc_stats(reports: &[Report]) -> bool {
+
+    return reports.iter().any(|report| report.parse_allocations.is_some());
+}
+
+
+/// Highest peak heap of the parse and the parts of `report`.
+fn peak_heap(report: &Report) -> Option<usize> {
+
+    return report.parse_allocations.iter().chain(report.parts.iter().filter_map(|part| part.allocations.as_ref())).map(|stats| stats.peak).max();
+}
+
+
+fn text(reports: &[Report]) -> String {
+
+    let mut output = String::new();
+
+    for report in reports {
+
+        if let Some(error) = &report.error {
+
+            output += &format!("day {} | FAILED: {}\n", report.label(), error);
+            continue;
+        }
+
+        for part in report.parts.iter() {
+
+            if let Some(error) = &part.error {
+
+                output += &format!("day {} | part {} | FAILED: {}\n", report.label(), part.part.number(), error);
+                continue;
+            }
+
+            output += &format!("day {} | part {} | result: {}, time: {}{}\n", report.label(), part.part.number(), part.answer, format_duration(part.time), alloc_summary(part.allocations));
+        }
+
+        output += &format!("day {} | parse: {}{}, total: {}\n", report.label(), format_duration(report.parse_time), alloc_summary(report.parse_allocations), format_duration(report.total_time()));
+    }
+
+    return output;
+}
+
+
+fn summary_table(reports: &[Report]) -> String {
+
+    let part_cell = |report: &Report, part: Part, cell: &dyn Fn(&PartReport) -> String| {
+        report.part(part).map(cell).unwrap_or_else(|| "-".to_owned())
+    };
+    let answer_cell = |report: &Report, part: Part| match report.error {
+        Some(_) => "FAILED".to_owned(),
+        None => part_cell(report, part, &|part| if part.error.is_some() { "FAILED".to_owned() } else { part.answer.clone() }),
+    };
+
+    let mut rows: Vec<Vec<String>> = reports.iter().map(|report| vec![
+        report.label(),
+        answer_cell(report, Part::One),
+        answer_cell(report, Part::Two),
+        format_duration(report.parse_time),
+        part_cell(report, Part::One, &|part| format_duration(part.time)),
+        part_cell(report, Part::Two, &|part| format_duration(part.time)),
+        format_duration(report.total_time()),
+    ]).collect();
+
+    let part_total = |part: Part| reports.iter().filter_map(|report| report.part(part)).map(|part| part.time).sum::<u128>();
+
+    rows.push(vec![
+        "total".to_owned(),
+        String::new(),
+        String::new(),
+        format_duration(reports.iter().map(|report| report.parse_time).sum()),
+        format_duration(part_total(Part::One)),
+        format_duration(part_total(Part::Two)),
+        format_duration(reports.iter().map(|report| report.total_time()).sum()),
+    ]);
+
+    let mut headers = vec!["day", "part 1", "part 2", "parse", "part 1 time", "part 2 time", "total"];
+
+    if has_alloc_stats(reports) {
+
+        headers.push("peak heap");
+        for (row, report) in rows.iter_mut().zip(reports) {
+
+            row.push(peak_heap(report).map(format_bytes).unwrap_or_else(|| "-".to_owned()));
+        }
+
+        let overall = reports.iter().filter_map(peak_heap).max();
+        rows.last_mut().unwrap().push(overall.map(format_bytes).unwrap_or_else(|| "-".to_owned()));
+    }
+
+    return table::render(&headers, &rows);
+}
+
+
+fn json_string(value: &str) -> String {
+
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for ch in value.chars() {
+
+        match ch {
+            '"' => output += "\\\"",
+            '\\' => output += "\\\\",
+            '\n' => output += "\\n",
+            '\r' => output += "\\r",
+            '\t' => output += "\\t",
+            ch if (ch as u32) < 0x20 => output += &format!("\\u{:04x}", ch as u32),
+            ch => output.push(ch),
+        }
+    }
+
+    output.push('"');
+    return output;
+}
+
+
+/// One object per day, input and part; times are integer nanoseconds.
+///
+/// A day that failed gets a single object with an `error` instead of its parts, a part that panicked an `error`
+/// instead of its `answer`. Allocation counts and peaks (in bytes) are added when they were measured.
+fn json(reports: &[Report]) -> String {
+
+    let profile = |report: &Report| report.profile.as_deref().map(json_string).unwrap_or_else(|| "null".to_owned());
+
+    let entries: Vec<String> = reports.iter().flat_map(|report| {
+
+        if let Some(error) = &report.error {
+
+            return vec![format!("  {{\"day\": {}, \"profile\": {}, \"error\": {}}}", report.day, profile(report), json_string(error))];
+        }
+
+        return report.parts.iter().map(|part| {
+            let outcome = match &part.error {
+                Some(error) => format!("\"error\": {}", json_string(error)),
+                None => format!("\"answer\": {}", json_string(&part.answer)),
+            };
+            let allocations = [("parse_", report.parse_allocations), ("", part.allocations)].iter()
+                .filter_map(|(prefix, stats)| stats.map(|stats| format!(
+                    ", \"{0}allocations\": {1}, \"{0}bytes\": {2}, \"{0}peak\": {3}", prefix, stats.allocations, stats.bytes, stats.peak
+                )))
+                .collect::<String>();
+            format!(
+                "  {{\"day\": {}, \"profile\": {}, \"part\": {}, {}, \"parse_ns\": {}, \"solve_ns\": {}{}}}",
+                report.day, profile(report), part.part.number(), outcome, report.parse_time, part.time, allocations
+            )
+        }).collect();
+    }).collect();
+
+    if entries.is_empty() {
+
+        return "[]\n".to_owned();
+    }
+
+    return format!("[\n{}\n]\n", entries.join(",\n"));
+}
+
+
+fn csv_field(value: &str) -> String {
+
+    if value.contains([',', '"', '\n', '\r']) {
+
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+
+    return value.to_owned();
+}
+
+
+/// Same columns as the JSON output, with a header row; a failed day is a single row with only `error` filled in
+/// and a failed part has an empty `answer`.
+fn csv(reports: &[Report]) -> String {
+
+    let with_allocations = has_alloc_stats(reports);
+    let allocation_fields = |stats: Option<AllocStats>| match stats {
+        Some(stats) => format!("{},{},{}", stats.allocations, stats.bytes, stats.peak),
+        None => ",,".to_owned(),
+    };
+
+    let mut output = "day,profile,part,answer,parse_ns,solve_ns,error".to_owned();
+    if with_allocations {
+
+        output += ",parse_allocations,parse_bytes,parse_peak,allocations,bytes,peak";
+    }
+    output.push('\n');
+
+    for report in reports {
+
+        let profile = report.profile.as_deref().map(csv_field).unwrap_or_default();
+
+        if let Some(error) = &report.error {
+
+            output += &format!("{},{},,,,,{}{}\n", report.day, profile, csv_field(error), if with_allocations { ",,,,,," } else { "" });
+            continue;
+        }
+
+        for part in report.parts.iter() {
+
+            let error = part.error.as_deref().map(csv_field).unwrap_or_default();
+            output += &format!("{},{},{},{},{},{},{}", report.day, profile, part.part.number(), csv_field(&part.answer), report.parse_time, part.time, error);
+
+            if with_allocations {
+
+                output += &format!(",{},{}", allocation_fields(report.parse_allocations), allocation_fields(part.allocations));
+            }
+            output.push('\n');
+        }
+    }
+
+    return output;
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn reports() -> Vec<Report> {
+
+        return vec![Report{
+            day: 2,
+            profile: None,
+            parse_time: 1500,
+            parse_allocations: None,
+            parts: vec![
+                PartReport{ part: Part::One, answer: "150".to_owned(), time: 20, allocations: None, error: None },
+                PartReport{ part: Part::Two, answer: "a \"b\", c".to_owned(), time: 30, allocations: None, error: None },
+            ],
+            error: None
+        }];
+    }
+
+    #[test]
+    fn json_output() {
+
+        assert_eq!(render(Format::Json, &reports()), "\
+[
+  {\"day\": 2, \"profile\": null, \"part\": 1, \"answer\": \"150\", \"parse_ns\": 1500, \"solve_ns\": 20},
+  {\"day\": 2, \"profile\": null, \"part\": 2, \"answer\": \"a \\\"b\\\", c\", \"parse_ns\": 1500, \"solve_ns\": 30}
+]
+");
+        assert_eq!(render(Format::Json, &[]), "[]\n");
+    }
+
+    #[test]
+    fn csv_output() {
+
+        assert_eq!(render(Format::Csv, &reports()), "\
+day,profile,part,answer,parse_ns,solve_ns,error
+2,,1,150,1500,20,
+2,,2,\"a \"\"b\"\", c\",1500,30,
+");
+    }
+
+    #[test]
+    fn profile_is_labelled() {
+
+        let mut reports = reports();
+        reports[0].profile = Some("alice".to_owned());
+
+        assert!(render(Format::Text, &reports).starts_with("day 2/alice | part 1 | result: 150"));
+        assert!(render(Format::Json, &reports).contains("\"profile\": \"alice\""));
+        assert!(render(Format::Csv, &reports).contains("\n2,alice,1,150,1500,20,\n"));
+    }
+
+    #[test]
+    fn failed_day_is_reported() {
+
+        let mut reports = reports();
+        reports.push(Report::failure(4, "day 4 input, line 3, column 1: bad"));
+
+        assert!(render(Format::Text, &reports).ends_with("day 4 | FAILED: day 4 input, line 3, column 1: bad\n"));
+        assert!(render(Format::Table, &reports).contains("\n4     | FAILED |   FAILED |"));
+        assert!(render(Format::Json, &reports).contains("{\"day\": 4, \"profile\": null, \"error\": \"day 4 input, line 3, column 1: bad\"}"));
+        assert!(render(Format::Csv, &reports).ends_with("\n4,,,,,,\"day 4 input, line 3, column 1: bad\"\n"));
+    }
+
+    #[test]
+    fn failed_part_is_reported() {
+
+        let mut reports = reports();
+        reports[0].parts[1] = PartReport{ part: Part::Two, answer: String::new(), time: 30, allocations: None, error: Some("panicked: oops".to_owned()) };
+
+        assert!(render(Format::Text, &reports).contains("day 2 | part 2 | FAILED: panicked: oops\n"));
+        assert!(render(Format::Table, &reports).contains("\n2     |    150 | FAILED |"));
+        assert!(render(Format::Json, &reports).contains("\"part\": 2, \"error\": \"panicked: oops\", \"parse_ns\""));
+        assert!(render(Format::Csv, &reports).ends_with("\n2,,2,,1500,30,panicked: oops\n"));
+    }
+
+    #[test]
+    fn allocations_are_reported_when_counted() {
+
+        let mut reports = reports();
+        reports[0].parse_allocations = Some(AllocStats{ allocations: 3, bytes: 4096, peak: 2048 });
+        reports[0].parts[0].allocations = Some(AllocStats{ allocations: 1, bytes: 16, peak: 16 });
+
+        assert!(render(Format::Text, &reports).contains("day 2 | part 1 | result: 150, time: 20 ns (1 allocations, 16 B allocated, peak 16 B)\n"));
+        assert!(render(Format::Text, &reports).contains("day 2 | parse: 1.50 µs (3 allocations, 4.00 KiB allocated, peak 2.00 KiB), total"));
+        assert!(render(Format::Table, &reports).lines().next().unwrap().ends_with("| peak heap"));
+        assert!(render(Format::Json, &reports).contains("\"solve_ns\": 20, \"parse_allocations\": 3, \"parse_bytes\": 4096, \"parse_peak\": 2048, \"allocations\": 1, \"bytes\": 16, \"peak\": 16}"));
+        assert!(render(Format::Csv, &reports).contains("\n2,,1,150,1500,20,,3,4096,2048,1,16,16\n"));
+        assert!(render(Format::Csv, &reports).contains("\n2,,2,\"a \"\"b\"\", c\",1500,30,,3,4096,2048,,,\n"));
+    }
+
+    #[test]
+    fn json_string_escapes_control_characters() {
+
+        assert_eq!(json_string("a\u{1}\tb"), "\"a\\u0001\\tb\"");
+    }
+}
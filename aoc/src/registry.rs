@@ -0,0 +1,260 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::{Arc, Once};
+
+use aoc_common::{Part, Rng, Solution, measure};
+
+use crate::allocator::{self, AllocStats};
+use crate::budget::{self, Limits};
+
+
+
+pub struct PartReport {
+
+    pub part: Part,
+    pub answer: String,
+    pub time: u128,
+    /// Only counted when built with the `alloc-stats` feature.
+    pub allocations: Option<AllocStats>,
+    /// Set, with an empty `answer`, when the part panicked.
+    pub error: Option<String>
+}
+
+/// Answers and timings (in nanoseconds) of one day run.
+pub struct Report {
+
+    pub day: u8,
+    pub profile: Option<String>,
+    pub parse_time: u128,
+    pub parse_allocations: Option<AllocStats>,
+    pub parts: Vec<PartReport>,
+    /// Why the day produced no answers, when its input could not be read or parsed.
+    pub error: Option<String>
+}
+
+impl Report {
+
+    pub fn failure(day: u8, error: impl ToString) -> Self {
+
+        return Self{
+            day,
+            profile: None,
+            parse_time: 0,
+            parse_allocations: None,
+            parts: vec![],
+            error: Some(error.to_string())
+        };
+    }
+
+    /// `7`, or `7/alice` when the input came from a named profile.
+    pub fn label(&self) -> String {
+
+        return match &self.profile {
+            Some(profile) => format!("{}/{}", self.day, profile),
+            None => self.day.to_string(),
+        };
+    }
+
+    /// Whether the day or any of its parts failed.
+    pub fn failed(&self) -> bool {
+
+        return self.error.is_some() || self.parts.iter().any(|part| part.error.is_some());
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartReport> {
+
+        return self.parts.iter().find(|report| report.part == part);
+    }
+
+    pub fn total_time(&self) -> u128 {
+
+        return self.parse_time + self.parts.iter().map(|report| report.time).sum::<u128>();
+    }
+}
+
+
+/// Parses the raw puzzle input once and runs every requested part, in order, each within the limits.
+pub type Solver = fn(&str, &[Part], &Limits) -> Result<Report, Box<dyn std::error::Error>>;
+
+pub struct Day {
+
+    pub number: u8,
+    pub solve: Solver,
+    pub generate: fn(usize, &mut Rng) -> String
+}
+
+impl Day {
+
+    const fn of<S>() -> Self
+    where
+        S: Solution,
+        S::Input: Send + Sync + 'static
+    {
+
+        return Self{
+            number: S::DAY,
+            solve: solve::<S>,
+            generate: S::generate
+        };
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_3::Day3>(),
+    Day::of::<day_4::Day4>(),
+    Day::of::<day_5::Day5>(),
+    Day::of::<day_6::Day6>(),
+    Day::of::<day_7::Day7>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+
+    return DAYS.iter().find(|day| day.number == number);
+}
+
+
+thread_local! {
+
+    /// Whether this thread is inside `catch_panic`, whose panics are reported instead of printed.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+
+    return payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_owned());
+}
+
+
+/// Runs `func`, turning a panic into its message and location so one broken solver cannot abort a whole run.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+
+        let default_hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+
+            if !CATCHING.get() {
+
+                return default_hook(info);
+            }
+
+            let location = info.location().map(|location| format!(" at {}:{}", location.file(), location.line())).unwrap_or_default();
+            CAUGHT.set(Some(format!("panicked{}: {}", location, panic_message(info.payload()))));
+        }));
+    });
+
+    CATCHING.set(true);
+    let result = catch_unwind(AssertUnwindSafe(func));
+    CATCHING.set(false);
+
+    return result.map_err(|payload| CAUGHT.take().unwrap_or_else(|| format!("panicked: {}", panic_message(&*payload))));
+}
+
+
+fn solve<S>(raw_input: &str, parts: &[Part], limits: &Limits) -> Result<Report, Box<dyn std::error::Error>>
+where
+    S: Solution,
+    S::Input: Send + Sync + 'static
+{
+
+    // workers may outlive this call when they exceed a limit, so they get their own copies
+    let raw_input = Arc::<str>::from(raw_input);
+
+    // time and allocations are measured inside the worker, which is the thread doing the work, so spawning it when
+    // there are limits does not count towards either
+    let parsed = budget::run(limits, move || catch_panic(|| measure(|| allocator::measure(|| S::parse(&raw_input)))));
+    let (parse_time, (parse_allocations, input)) = parsed.map_err(|error| format!("parse {}", error))?;
+    let input = Arc::new(input?);
+
+    let parts = parts.iter().copied().map(|part| {
+        let input = input.clone();
+        // a part that fails has no time of its own, so it reports how long it ran before failing
+        let (elapsed, answer) = measure(|| budget::run(limits, move || catch_panic(|| measure(|| allocator::measure(|| S::solve(&input, part).to_string())))));
+        match answer {
+            Ok((time, (allocations, answer))) => PartReport{ part, answer, time, allocations, error: None },
+            Err(error) => PartReport{ part, answer: String::new(), time: elapsed, allocations: None, error: Some(error) },
+        }
+    }).collect();
+
+    return Ok(Report{
+        day: S::DAY,
+        profile: None,
+        parse_time,
+        parse_allocations,
+        parts,
+        error: None
+    });
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use aoc_common::ParseError;
+
+    struct Broken;
+
+    impl Solution for Broken {
+
+        const DAY: u8 = 99;
+
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+            if raw_input.is_empty() {
+
+                panic!("no input");
+            }
+
+            return Ok(raw_input.lines().map(|line| line.parse().unwrap()).collect());
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer {
+
+            return input.iter().sum();
+        }
+
+        fn part2(input: &Self::Input) -> Self::Answer {
+
+            return input[10];
+        }
+
+        fn generate(_size: usize, _rng: &mut Rng) -> String {
+
+            return String::new();
+        }
+    }
+
+    #[test]
+    fn panicking_part_is_recorded() {
+
+        let report = solve::<Broken>("1\n2\n", &Part::ALL, &Limits::default()).unwrap();
+
+        assert!(report.failed());
+        assert_eq!((report.parts[0].answer.as_str(), &report.parts[0].error), ("3", &None));
+        let error = report.parts[1].error.as_deref().unwrap();
+        assert!(error.starts_with("panicked at aoc/src/registry.rs:"), "{}", error);
+        assert!(error.contains(": index out of bounds"), "{}", error);
+    }
+
+    #[test]
+    fn panicking_parse_is_an_error() {
+
+        let error = solve::<Broken>("", &Part::ALL, &Limits::default()).err().unwrap().to_string();
+        assert!(error.starts_with("parse panicked at aoc/src/registry.rs:") && error.ends_with(": no input"), "{}", error);
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::Path;
+
+
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+
+fn render(template: &str, day: u8) -> String {
+
+    return template.replace("{day}", &day.to_string());
+}
+
+
+/// Inserts `line` right after the last line of `path` that starts with `after`.
+fn insert_after_last(path: &Path, after: &str, line: &str) -> Result<(), Box<dyn std::error::Error>> {
+
+    let content = aoc_common::read_file(path)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let position = lines.iter().rposition(|current| current.trim_start().starts_with(after))
+        .ok_or_else(|| format!("{}: no line starting with `{}` to insert after", path.display(), after))?;
+
+    let indentation = &lines[position][..lines[position].len() - lines[position].trim_start().len()];
+    let line = format!("{}{}", indentation, line);
+    lines.insert(position + 1, &line);
+
+    std::fs::write(path, lines.join("\n") + "\n")?;
+    return Ok(());
+}
+
+
+/// Generates `day_<day>/rust` under `root` and wires it into the workspace, the runner registry and the benches.
+pub fn create_day(root: &Path, day: u8) -> Result<(), Box<dyn std::error::Error>> {
+
+    if !(1..=25).contains(&day) {
+
+        return Err(format!("invalid day: {}", day).into());
+    }
+
+    let crate_dir = root.join(format!("day_{}", day)).join("rust");
+    if crate_dir.exists() {
+
+        return Err(format!("{} already exists", crate_dir.display()).into());
+    }
+
+    std::fs::create_dir_all(crate_dir.join("src"))?;
+    std::fs::write(crate_dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day))?;
+    std::fs::write(crate_dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day))?;
+    std::fs::write(crate_dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, day))?;
+    std::fs::write(crate_dir.join("input.txt"), "")?;
+
+    insert_after_last(&root.join("Cargo.toml"), "day_", &format!("day_{0} = {{ path = \"day_{0}/rust\" }}", day))?;
+    insert_after_last(&root.join("aoc").join("Cargo.toml"), "day_", &format!("day_{}.workspace = true", day))?;
+    insert_after_last(&root.join("aoc").join("src").join("registry.rs"), "Day::of::<", &format!("Day::of::<day_{0}::Day{0}>(),", day))?;
+    insert_after_last(&root.join("aoc").join("benches").join("days.rs"), "bench_day::<", &format!("bench_day::<day_{0}::Day{0}>(c);", day))?;
+
+    return Ok(());
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use aoc_common::read_file;
+
+
+    /// The files `create_day` edits, relative to the repository root.
+    const WIRED_FILES: [&str; 4] = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs", "aoc/benches/days.rs"];
+
+    /// A scratch copy of the files `create_day` edits, taken from this repository.
+    fn temp_root() -> tempfile::TempDir {
+
+        let dir = tempfile::tempdir().unwrap();
+
+        for file in WIRED_FILES {
+
+            std::fs::create_dir_all(dir.path().join(file).parent().unwrap()).unwrap();
+            std::fs::copy(crate::repo_root().join(file), dir.path().join(file)).unwrap();
+        }
+
+        return dir;
+    }
+
+    /// `original` with `line` inserted right after its `after` line, which must appear exactly once.
+    fn with_line_after(original: &str, after: &str, line: &str) -> String {
+
+        assert_eq!(original.matches(after).count(), 1, "`{}` is not unique", after);
+        return original.replacen(after, &format!("{}\n{}", after, line), 1);
+    }
+
+    #[test]
+    fn creates_crate_and_wires_it_into_the_workspace() {
+
+        let dir = temp_root();
+        let root = dir.path();
+        create_day(root, 8).unwrap();
+
+        let crate_dir = root.join("day_8").join("rust");
+        assert!(read_file(crate_dir.join("Cargo.toml")).unwrap().contains("name = \"day_8\""));
+        assert!(read_file(crate_dir.join("src").join("lib.rs")).unwrap().contains("impl Solution for Day8 {"));
+        assert!(read_file(crate_dir.join("src").join("main.rs")).unwrap().contains("use day_8::"));
+        assert_eq!(read_file(crate_dir.join("input.txt")).unwrap(), "");
+
+        let expected = [
+            ("day_7 = { path = \"day_7/rust\" }", "day_8 = { path = \"day_8/rust\" }"),
+            ("day_7.workspace = true", "day_8.workspace = true"),
+            ("    Day::of::<day_7::Day7>(),", "    Day::of::<day_8::Day8>(),"),
+            ("    bench_day::<day_7::Day7>(c);", "    bench_day::<day_8::Day8>(c);"),
+        ];
+
+        for (file, (after, line)) in WIRED_FILES.iter().zip(expected) {
+
+            let original = read_file(crate::repo_root().join(file)).unwrap();
+            assert_eq!(read_file(root.join(file)).unwrap(), with_line_after(&original, after, line), "{}", file);
+        }
+    }
+
+    #[test]
+    fn rejects_existing_day() {
+
+        let dir = temp_root();
+        let root = dir.path();
+        create_day(root, 9).unwrap();
+
+        let error = create_day(root, 9).unwrap_err();
+        assert!(error.to_string().ends_with("day_9/rust already exists"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_days_outside_the_calendar() {
+
+        let dir = temp_root();
+        let root = dir.path();
+
+        for day in [0, 26] {
+
+            assert_eq!(create_day(root, day).unwrap_err().to_string(), format!("invalid day: {}", day));
+        }
+
+        assert!(!root.join("day_0").exists() && !root.join("day_26").exists());
+    }
+}
@@ -0,0 +1,57 @@
+/// Renders `rows` as a plain-text table with every column padded to its widest cell.
+///
+/// The first column is left-aligned, the remaining ones right-aligned since they hold numbers.
+pub fn render(headers: &[&str], rows: &[Vec<String>]) -> String {
+
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+
+    for row in rows {
+
+        for (width, cell) in widths.iter_mut().zip(row) {
+
+            *width = usize::max(*width, cell.chars().count());
+        }
+    }
+
+    let render_row = |cells: &mut dyn Iterator<Item = &str>| {
+
+        let line = cells.zip(&widths).enumerate().map(|(idx, (cell, width))| {
+            if idx == 0 { format!("{:<width$}", cell, width = width) } else { format!("{:>width$}", cell, width = width) }
+        }).collect::<Vec<_>>();
+
+        return line.join(" | ").trim_end().to_owned();
+    };
+
+    let mut output = render_row(&mut headers.iter().copied()) + "\n";
+    output += &(widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().join("-+-") + "\n");
+
+    for row in rows {
+
+        output += &(render_row(&mut row.iter().map(|cell| cell.as_str())) + "\n");
+    }
+
+    return output;
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn render_aligns_columns() {
+
+        let rows = vec![
+            vec!["1".to_owned(), "7".to_owned()],
+            vec!["total".to_owned(), "1234".to_owned()],
+        ];
+
+        assert_eq!(render(&["day", "answer"], &rows), "\
+day   | answer
+------+-------
+1     |      7
+total |   1234
+");
+    }
+}
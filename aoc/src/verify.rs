@@ -0,0 +1,194 @@
+use std::path::PathBuf;
+
+use aoc_common::{Part, read_input_file};
+
+use crate::answers::Answers;
+use crate::budget::Limits;
+use crate::registry::Day;
+
+
+
+/// One input of a day together with the file holding its known answers.
+struct Case {
+
+    label: String,
+    input_path: PathBuf,
+    answers_path: PathBuf
+}
+
+
+/// The day's own `input.txt` and `answers.toml`, the ones committed next to its crate.
+fn own_case(day: &Day) -> Case {
+
+    return Case{
+        label: day.number.to_string(),
+        input_path: crate::day_dir(day.number).join("input.txt"),
+        answers_path: crate::day_dir(day.number).join("answers.toml")
+    };
+}
+
+
+/// The day's own case, then every profile under `inputs/day_<day>`,
+/// whose answers live next to it as `<profile>.answers.toml`.
+fn cases(day: &Day) -> Result<Vec<Case>, Box<dyn std::error::Error>> {
+
+    let mut cases = vec![own_case(day)];
+
+    let cache = crate::input_cache();
+
+    for profile in cache.profiles(day.number)? {
+
+        let input_path = cache.path(day.number, &profile);
+        let answers_path = input_path.with_extension("answers.toml");
+
+        cases.push(Case{
+            label: format!("{}/{}", day.number, profile),
+            input_path,
+            answers_path
+        });
+    }
+
+    return Ok(cases);
+}
+
+
+/// Runs both parts of `day` against one case, printing each check and returning how many of them failed.
+fn check(day: &Day, case: &Case, record: bool) -> Result<usize, Box<dyn std::error::Error>> {
+
+    let mut failures = 0;
+
+    let results = match read_input_file(&case.input_path) {
+        Ok(Some(raw_input)) => (day.solve)(&raw_input, &Part::ALL, &Limits::default()),
+        Ok(None) => {
+            println!("day {} | skipped: no {} or it is empty", case.label, case.input_path.display());
+            return Ok(failures);
+        },
+        Err(error) => Err(error.into()),
+    };
+
+    let results = match results {
+        Ok(report) => report.parts.into_iter().map(|part| match part.error {
+            Some(error) => Err(error),
+            None => Ok(part.answer),
+        }).collect::<Vec<_>>(),
+        Err(error) => {
+            failures += 1;
+            println!("day {} | FAILED: {}", case.label, error);
+            return Ok(failures);
+        }
+    };
+
+    // a part that panicked has nothing to record or compare
+    for (part, result) in Part::ALL.iter().zip(results.iter()) {
+
+        if let Err(error) = result {
+
+            failures += 1;
+            println!("day {} | part {} | FAILED: {}", case.label, part.number(), error);
+        }
+    }
+
+    if record {
+
+        if results.iter().any(|result| result.is_err()) {
+
+            return Ok(failures);
+        }
+
+        let mut answers = Answers::default();
+        for (part, result) in Part::ALL.iter().zip(results.into_iter().flatten()) {
+
+            answers.set(*part, result);
+        }
+
+        answers.save(&case.answers_path)?;
+        println!("day {} | recorded {}", case.label, case.answers_path.display());
+        return Ok(failures);
+    }
+
+    let expected = match Answers::load(&case.answers_path)? {
+        Some(answers) => answers,
+        None => {
+            println!("day {} | skipped: no {}", case.label, case.answers_path.display());
+            return Ok(failures);
+        }
+    };
+
+    for (part, actual) in Part::ALL.iter().zip(results) {
+
+        let Ok(actual) = actual else { continue };
+
+        match expected.get(*part) {
+            Some(expected) if expected == actual => println!("day {} | part {} | ok", case.label, part.number()),
+            Some(expected) => {
+                failures += 1;
+                println!("day {} | part {} | MISMATCH", case.label, part.number());
+                println!("    - expected: {}", expected);
+                println!("    + actual:   {}", actual);
+            },
+            None => println!("day {} | part {} | no recorded answer (got {})", case.label, part.number(), actual),
+        }
+    }
+
+    return Ok(failures);
+}
+
+
+/// Runs both parts of every day against each of its inputs and compares them with the recorded answers.
+///
+/// With `record` set the answers files are (re)written from the current results instead.
+pub fn verify(days: &[&Day], record: bool) -> Result<(), Box<dyn std::error::Error>> {
+
+    let mut failures = 0;
+
+    for day in days {
+
+        for case in cases(day)? {
+
+            failures += check(day, &case, record)?;
+        }
+    }
+
+    if failures > 0 {
+
+        return Err(format!("{} check(s) failed", failures).into());
+    }
+
+    return Ok(());
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::registry;
+
+    /// The days whose `input.txt` and `answers.toml` are committed; day 4's input is not.
+    const COMMITTED_DAYS: [u8; 6] = [1, 2, 3, 5, 6, 7];
+
+    // profiles under `inputs/` are local to each checkout, so only the committed cases are checked
+    #[test]
+    fn recorded_answers_still_hold() {
+
+        for number in COMMITTED_DAYS {
+
+            let day = registry::find(number).unwrap();
+            let case = own_case(day);
+
+            assert!(case.input_path.is_file() && case.answers_path.is_file(), "day {} has no committed input", number);
+            assert_eq!(check(day, &case, false).unwrap(), 0, "day {}", number);
+        }
+    }
+
+    #[test]
+    fn first_case_is_the_day_input() {
+
+        let case = &cases(registry::find(7).unwrap()).unwrap()[0];
+
+        assert_eq!(case.label, "7");
+        assert_eq!(case.input_path, crate::day_dir(7).join("input.txt"));
+        assert_eq!(case.answers_path, crate::day_dir(7).join("answers.toml"));
+    }
+}
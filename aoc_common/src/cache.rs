@@ -0,0 +1,235 @@
+use std::path::{Path, PathBuf};
+
+use crate::input::{InputError, read_file};
+
+
+
+/// Somewhere puzzle inputs can be obtained from when they are not cached yet.
+pub trait Fetcher {
+
+    fn fetch(&self, day: u8, profile: &str) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+
+/// Default fetcher: copies inputs from a local mirror laid out like the cache, `<root>/day_<day>/<profile>.txt`.
+pub struct MirrorFetcher {
+
+    root: PathBuf
+}
+
+impl MirrorFetcher {
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+
+        return Self{
+            root: root.into()
+        };
+    }
+}
+
+impl Fetcher for MirrorFetcher {
+
+    fn fetch(&self, day: u8, profile: &str) -> Result<String, Box<dyn std::error::Error>> {
+
+        return Ok(read_file(input_path(&self.root, day, profile))?);
+    }
+}
+
+
+#[derive(Debug)]
+pub enum CacheError {
+
+    Read(InputError),
+    Write(PathBuf, std::io::Error),
+    Fetch{ day: u8, profile: String, error: Box<dyn std::error::Error> },
+    Empty{ day: u8, profile: String }
+}
+
+impl std::fmt::Display for CacheError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self {
+            CacheError::Read(error) => write!(f, "{}", error),
+            CacheError::Write(path, error) => write!(f, "cannot write {}: {}", path.display(), error),
+            CacheError::Fetch{ day, profile, error } => write!(f, "cannot fetch day {} input for `{}`: {}", day, profile, error),
+            CacheError::Empty{ day, profile } => write!(f, "day {} input for `{}` is empty", day, profile),
+        };
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+
+/// Where the input of `day` for `profile` lives under `root`.
+pub fn input_path(root: &Path, day: u8, profile: &str) -> PathBuf {
+
+    return root.join(format!("day_{}", day)).join(format!("{}.txt", profile));
+}
+
+
+/// Puzzle inputs cached on disk by day and profile, filled from a [`Fetcher`] on a miss.
+///
+/// Empty inputs are never accepted: an empty cached file is fetched again and an empty fetch is an error.
+pub struct InputCache<F: Fetcher> {
+
+    dir: PathBuf,
+    fetcher: F
+}
+
+impl<F: Fetcher> InputCache<F> {
+
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+
+        return Self{
+            dir: dir.into(),
+            fetcher
+        };
+    }
+
+    pub fn path(&self, day: u8, profile: &str) -> PathBuf {
+
+        return input_path(&self.dir, day, profile);
+    }
+
+    /// Names of the profiles cached for `day`, sorted; a day without a cache directory has none.
+    pub fn profiles(&self, day: u8) -> Result<Vec<String>, CacheError> {
+
+        let dir = self.dir.join(format!("day_{}", day));
+        if !dir.exists() {
+
+            return Ok(vec![]);
+        }
+
+        let read_error = |error| CacheError::Read(InputError{ source: dir.display().to_string(), error });
+        let mut profiles = vec![];
+
+        for entry in std::fs::read_dir(&dir).map_err(read_error)? {
+
+            let path = entry.map_err(read_error)?.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+
+                if let Some(profile) = path.file_stem().and_then(|stem| stem.to_str()) {
+
+                    profiles.push(profile.to_owned());
+                }
+            }
+        }
+
+        profiles.sort();
+        return Ok(profiles);
+    }
+
+    pub fn get(&self, day: u8, profile: &str) -> Result<String, CacheError> {
+
+        let path = self.path(day, profile);
+
+        if path.exists() {
+
+            let cached = read_file(&path).map_err(CacheError::Read)?;
+            if !cached.trim().is_empty() {
+
+                return Ok(cached);
+            }
+        }
+
+        let fetched = self.fetcher.fetch(day, profile).map_err(|error| CacheError::Fetch{ day, profile: profile.to_owned(), error })?;
+        if fetched.trim().is_empty() {
+
+            return Err(CacheError::Empty{ day, profile: profile.to_owned() });
+        }
+
+        let parent = path.parent().expect("cache paths always have a day directory");
+        std::fs::create_dir_all(parent).map_err(|error| CacheError::Write(parent.to_owned(), error))?;
+        std::fs::write(&path, &fetched).map_err(|error| CacheError::Write(path.clone(), error))?;
+
+        return Ok(fetched);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use std::cell::Cell;
+
+
+    struct CountingFetcher {
+
+        content: &'static str,
+        calls: Cell<u32>
+    }
+
+    impl Fetcher for CountingFetcher {
+
+        fn fetch(&self, _day: u8, _profile: &str) -> Result<String, Box<dyn std::error::Error>> {
+
+            self.calls.set(self.calls.get() + 1);
+            return Ok(self.content.to_owned());
+        }
+    }
+
+    #[test]
+    fn fetches_once_then_reads_from_cache() {
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path(), CountingFetcher{ content: "3,4,3,1,2\n", calls: Cell::new(0) });
+
+        assert_eq!(cache.get(6, "alice").unwrap(), "3,4,3,1,2\n");
+        assert_eq!(cache.get(6, "alice").unwrap(), "3,4,3,1,2\n");
+        assert_eq!(cache.fetcher.calls.get(), 1);
+        assert_eq!(read_file(dir.path().join("day_6").join("alice.txt")).unwrap(), "3,4,3,1,2\n");
+    }
+
+    #[test]
+    fn empty_cached_input_is_fetched_again() {
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("day_6")).unwrap();
+        std::fs::write(dir.path().join("day_6").join("bob.txt"), "\n").unwrap();
+
+        let cache = InputCache::new(dir.path(), CountingFetcher{ content: "1,2\n", calls: Cell::new(0) });
+        assert_eq!(cache.get(6, "bob").unwrap(), "1,2\n");
+        assert_eq!(cache.fetcher.calls.get(), 1);
+    }
+
+    #[test]
+    fn empty_fetched_input_is_rejected() {
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path(), CountingFetcher{ content: "  \n", calls: Cell::new(0) });
+        assert!(matches!(cache.get(6, "carol"), Err(CacheError::Empty{ day: 6, .. })));
+    }
+
+    #[test]
+    fn profiles_lists_cached_inputs() {
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("day_7")).unwrap();
+        for file in ["bob.txt", "alice.txt", "alice.answers.toml"] {
+
+            std::fs::write(dir.path().join("day_7").join(file), "1\n").unwrap();
+        }
+
+        let cache = InputCache::new(dir.path(), MirrorFetcher::new(dir.path().join("mirror")));
+        assert_eq!(cache.profiles(7).unwrap(), vec!["alice", "bob"]);
+        assert!(cache.profiles(8).unwrap().is_empty());
+    }
+
+    #[test]
+    fn mirror_fetcher_reads_mirror_layout() {
+
+        let mirror = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(mirror.path().join("day_7")).unwrap();
+        std::fs::write(mirror.path().join("day_7").join("alice.txt"), "16,1,2\n").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path(), MirrorFetcher::new(mirror.path()));
+        assert_eq!(cache.get(7, "alice").unwrap(), "16,1,2\n");
+
+        let error = cache.get(7, "dave").unwrap_err();
+        assert!(error.to_string().starts_with("cannot fetch day 7 input for `dave`: cannot read "));
+    }
+}
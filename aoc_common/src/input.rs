@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::io::Read;
+
+
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+
+    File(PathBuf),
+    Stdin,
+    Memory(String)
+}
+
+impl InputSource {
+
+    /// Interprets a command line argument: `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+
+        return match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        };
+    }
+
+    /// Uses the first command line argument, defaulting to `./input.txt` when there is none.
+    pub fn from_args() -> Self {
+
+        return Self::from_arg(&std::env::args().nth(1).unwrap_or("./input.txt".to_owned()));
+    }
+
+    pub fn read(self) -> Result<String, InputError> {
+
+        return match self {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => read_stdin(),
+            InputSource::Memory(content) => Ok(content),
+        };
+    }
+}
+
+
+/// An input that could not be read, naming the file (or stdin) it came from.
+pub struct InputError {
+
+    pub source: String,
+    pub error: std::io::Error
+}
+
+impl std::fmt::Display for InputError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return write!(f, "cannot read {}: {}", self.source, self.error);
+    }
+}
+
+// `main` functions returning `Result` print the error with `Debug`, so keep it as readable as `Display`.
+impl std::fmt::Debug for InputError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return std::fmt::Display::fmt(self, f);
+    }
+}
+
+impl std::error::Error for InputError {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+
+        return Some(&self.error);
+    }
+}
+
+
+pub fn read_file(path: impl AsRef<Path>) -> Result<String, InputError> {
+
+    let path = path.as_ref();
+    let with_path = |error: std::io::Error| InputError{ source: path.display().to_string(), error };
+
+    let mut file = File::open(path).map_err(with_path)?;
+    let file_size = file.metadata().map_err(with_path)?.len();
+    let mut output_string = String::with_capacity(file_size as usize);
+    file.read_to_string(&mut output_string).map_err(with_path)?;
+
+    return Ok(output_string);
+}
+
+/// Reads a day's input file, with `None` when it is missing or blank: `aoc new` leaves an empty `input.txt` behind
+/// until the puzzle input is pasted in, and runners skip such a day instead of failing it.
+pub fn read_input_file(path: impl AsRef<Path>) -> Result<Option<String>, InputError> {
+
+    return match read_file(path.as_ref()) {
+        Ok(content) if content.trim().is_empty() => Ok(None),
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    };
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+
+    let mut output_string = String::new();
+    std::io::stdin().read_to_string(&mut output_string).map_err(|error| InputError{ source: "<stdin>".to_owned(), error })?;
+
+    return Ok(output_string);
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn from_arg_treats_dash_as_stdin() {
+
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("input.txt"), InputSource::File("input.txt".into()));
+    }
+
+    #[test]
+    fn memory_source_returns_its_content() {
+
+        assert_eq!(InputSource::Memory("1\n2\n".to_owned()).read().unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn missing_file_error_names_the_path() {
+
+        let error = read_file("./does/not/exist.txt").unwrap_err();
+        assert!(error.to_string().starts_with("cannot read ./does/not/exist.txt: "));
+        assert_eq!(format!("{:?}", error), error.to_string());
+    }
+}
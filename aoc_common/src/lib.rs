@@ -0,0 +1,13 @@
+mod cache;
+mod input;
+mod parse;
+mod random;
+mod solution;
+mod timing;
+
+pub use cache::{CacheError, Fetcher, InputCache, MirrorFetcher, input_path};
+pub use input::{InputError, InputSource, read_file, read_input_file, read_stdin};
+pub use parse::{Line, ParseError, comma_separated, comma_separated_with, empty_input, lines};
+pub use random::Rng;
+pub use solution::{Part, Solution};
+pub use timing::{format_duration, measure};
@@ -0,0 +1,167 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+
+
+/// A malformed puzzle input, pointing at the offending text.
+///
+/// `line` and `column` are 1-based; `column` counts bytes, which is the same as characters for puzzle inputs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String
+}
+
+impl Display for ParseError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return write!(f, "day {} input, line {}, column {}: {} (found `{}`)", self.day, self.line, self.column, self.message, self.text);
+    }
+}
+
+// Printed by `main` functions returning `Result`, like `InputError`.
+impl std::fmt::Debug for ParseError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return Display::fmt(self, f);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+
+/// A line of puzzle input together with where it sits in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+
+    /// Builds an error about `token`, which should be a slice of `self.text` so its column can be found.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        let column = match offset.checked_add(token.len()) {
+            Some(end) if end <= self.text.len() => offset + 1,
+            _ => 1,
+        };
+
+        return ParseError{
+            day: self.day,
+            line: self.number,
+            column,
+            text: token.to_owned(),
+            message: message.into()
+        };
+    }
+
+    /// Parses `token` (a slice of `self.text`), reporting failures at its position.
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display
+    {
+
+        return token.parse().map_err(|error| self.error(token, format!("invalid number: {}", error)));
+    }
+}
+
+
+/// Splits `input` into numbered lines, the same way `str::lines` does.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+
+    return input.lines().enumerate().map(move |(idx, text)| Line{ day, number: idx + 1, text });
+}
+
+
+/// The error for an input holding nothing but blank lines, which no day can solve.
+pub fn empty_input(day: u8, input: &str) -> ParseError {
+
+    return Line{ day, number: 1, text: input }.error(input, "empty input");
+}
+
+
+/// Parses a single line of comma separated values, as used by the days whose input is one list.
+///
+/// Blank lines around the list are ignored; empty input and extra non-blank lines are errors.
+pub fn comma_separated<T>(day: u8, input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display
+{
+
+    return comma_separated_with(day, input, |line, token| line.parse(token));
+}
+
+
+/// Like [`comma_separated`], but turns each trimmed token into a value with `parse`, for days that also check ranges.
+pub fn comma_separated_with<T>(day: u8, input: &str, parse: impl Fn(&Line, &str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+
+    let mut non_blank = lines(day, input).filter(|line| !line.text.trim().is_empty());
+
+    let line = match non_blank.next() {
+        Some(line) => line,
+        None => return Err(empty_input(day, input)),
+    };
+
+    if let Some(extra) = non_blank.next() {
+
+        return Err(extra.error(extra.text, "expected a single line of comma separated values"));
+    }
+
+    return line.text.trim().split(',').map(|token| parse(&line, token.trim())).collect();
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn error_points_at_token() {
+
+        let line = lines(2, "forward 5\nup x\n").nth(1).unwrap();
+        let error = line.parse::<u64>(&line.text[3..]).unwrap_err();
+
+        assert_eq!((error.day, error.line, error.column), (2, 2, 4));
+        assert_eq!(error.text, "x");
+        assert_eq!(error.to_string(), "day 2 input, line 2, column 4: invalid number: invalid digit found in string (found `x`)");
+    }
+
+    #[test]
+    fn error_on_foreign_token_uses_first_column() {
+
+        let text = "abc".to_owned();
+        let line = Line{ day: 1, number: 3, text: &text };
+        assert_eq!(line.error("unrelated", "bad").column, 1);
+    }
+
+    #[test]
+    fn comma_separated_values() {
+
+        assert_eq!(comma_separated::<u8>(6, "3,4,3,1,2\n").unwrap(), vec![3, 4, 3, 1, 2]);
+        assert_eq!(comma_separated::<u8>(6, "\n3, 4\n\n").unwrap(), vec![3, 4]);
+    }
+
+    #[test]
+    fn comma_separated_errors() {
+
+        let error = comma_separated::<u8>(6, "3,4,x,1\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "x"));
+
+        assert_eq!(comma_separated::<u8>(6, "").unwrap_err().message, "empty input");
+        assert_eq!(comma_separated::<u8>(6, "1,2\n3\n").unwrap_err().line, 2);
+    }
+}
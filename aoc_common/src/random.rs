@@ -0,0 +1,83 @@
+/// Small seeded pseudo random generator (SplitMix64) for synthetic puzzle inputs.
+///
+/// The same seed always produces the same sequence, on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+
+    state: u64
+}
+
+impl Rng {
+
+    pub fn new(seed: u64) -> Self {
+
+        return Self{
+            state: seed
+        };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        return z ^ (z >> 31);
+    }
+
+    /// A value in `0..bound`; `bound` must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+
+        // the modulo bias is negligible for the small bounds inputs are generated with
+        return self.next_u64() % bound;
+    }
+
+    /// A value in `min..=max`.
+    pub fn between(&mut self, min: u64, max: u64) -> u64 {
+
+        return min + self.below(max - min + 1);
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+
+        for idx in (1..items.len()).rev() {
+
+            items.swap(idx, self.below(idx as u64 + 1) as usize);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn between_stays_in_range() {
+
+        let mut rng = Rng::new(1);
+        assert!((0..1000).map(|_| rng.between(3, 5)).all(|value| (3..=5).contains(&value)));
+    }
+
+    #[test]
+    fn shuffle_keeps_every_item() {
+
+        let mut items: Vec<u32> = (0..50).collect();
+        Rng::new(3).shuffle(&mut items);
+
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use crate::{ParseError, Rng};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+
+    One,
+    Two
+}
+
+impl Part {
+
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+
+        return match self {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+    }
+}
+
+
+/// Common interface every day implements, so runners, benches and tests can be written once.
+pub trait Solution {
+
+    const DAY: u8;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+
+    /// A synthetic input of `size` records (lines, numbers or boards) that `parse` accepts.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+
+        return match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        };
+    }
+}
@@ -0,0 +1,39 @@
+/// Runs `func` once and returns the elapsed wall-clock time in nanoseconds together with its output.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (u128, T) {
+
+    let before = std::time::Instant::now();
+    let output = func();
+    let after = std::time::Instant::now();
+
+    return ((after - before).as_nanos(), output);
+}
+
+
+/// Formats a duration given in nanoseconds with the largest unit that keeps it at or above one.
+pub fn format_duration(nanos: u128) -> String {
+
+    return match nanos {
+        0..=999 => format!("{} ns", nanos),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn format_duration_scales_units() {
+
+        assert_eq!(format_duration(0), "0 ns");
+        assert_eq!(format_duration(999), "999 ns");
+        assert_eq!(format_duration(1_000), "1.00 µs");
+        assert_eq!(format_duration(532_966), "532.97 µs");
+        assert_eq!(format_duration(18_765_134), "18.77 ms");
+        assert_eq!(format_duration(2_500_000_000), "2.50 s");
+    }
+}
@@ -0,0 +1,35 @@
+//! Scalar against chunked increase counting on 10 million generated readings:
+//!
+//!     cargo bench -p day_1 --bench increases
+
+use std::hint::black_box;
+
+use aoc_common::Rng;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use day_1::{generate_input, parse_input};
+use day_1::simd::{count_increases, count_increases_scalar};
+
+
+
+const READINGS: usize = 10_000_000;
+
+
+fn increases(c: &mut Criterion) {
+
+    let input = parse_input(&generate_input(READINGS, &mut Rng::new(1))).unwrap();
+
+    for window in [1, 3] {
+
+        let mut group = c.benchmark_group(format!("increases_window_{}", window));
+        group.throughput(Throughput::Elements(READINGS as u64));
+
+        group.bench_function("scalar", |b| b.iter(|| count_increases_scalar(black_box(&input), window)));
+        group.bench_function("chunked", |b| b.iter(|| count_increases(black_box(&input), window)));
+
+        group.finish();
+    }
+}
+
+
+criterion_group!(benches, increases);
+criterion_main!(benches);
@@ -0,0 +1,228 @@
+pub mod simd;
+pub mod stats;
+pub mod stream;
+
+use aoc_common::{Line, ParseError, Rng, Solution};
+
+
+
+pub fn parse_reading(line: Line) -> Result<u32, ParseError> {
+
+    return line.parse::<u32>(line.text.trim());
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+
+    let output = aoc_common::lines(1, input).map(parse_reading).collect::<Result<_, _>>()?;
+    return Ok(output);
+}
+
+/// Sonar depths drifting mostly downwards, like the real readings.
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    let mut depth = rng.between(100, 200);
+    let mut output = String::with_capacity(size * 5);
+
+    for _ in 0..size {
+
+        depth = (depth + rng.below(40)).saturating_sub(15);
+        output += &format!("{}\n", depth);
+    }
+
+    return output;
+}
+
+/// Counts how often the sum of `window` consecutive readings is larger than the sum of the window `stride` readings
+/// before it, so `(1, 1)` compares single readings and `(3, 1)` overlapping triples.
+///
+/// Runs in O(n) without building the window sums: with a stride of one, two neighbouring windows share all but their
+/// ends and comparing them is comparing `input[i]` against `input[i + window]`, which [`simd::count_increases`] does
+/// many readings at a time; other strides keep two running sums.
+pub fn count_window_increases(input: &[u32], window: usize, stride: usize) -> u64 {
+
+    if window == 0 || stride == 0 || input.len() < window + stride {
+
+        return 0;
+    }
+
+    if stride == 1 {
+
+        return simd::count_increases(input, window);
+    }
+
+    let mut earlier: u64 = input[..window].iter().map(|reading| *reading as u64).sum();
+    let mut later: u64 = input[stride..stride + window].iter().map(|reading| *reading as u64).sum();
+    let mut count = 0;
+
+    for idx in 0..=input.len() - window - stride {
+
+        if later > earlier {
+
+            count += 1;
+        }
+
+        if idx + window + stride < input.len() {
+
+            earlier = earlier + input[idx + window] as u64 - input[idx] as u64;
+            later = later + input[idx + stride + window] as u64 - input[idx + stride] as u64;
+        }
+    }
+
+    return count;
+}
+
+pub fn count_increase(input: &[u32]) -> u64 {
+
+    return count_window_increases(input, 1, 1);
+}
+
+pub fn count_three_measurement_window_increase(input: &[u32]) -> u64 {
+
+    return count_window_increases(input, 3, 1);
+}
+
+
+pub struct Day1;
+
+impl Solution for Day1 {
+
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        return count_increase(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return count_three_measurement_window_increase(input);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 7);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), 5);
+    }
+
+    #[test]
+    fn count_increase_on_short_input() {
+
+        assert_eq!(count_increase(&[]), 0);
+        assert_eq!(count_increase(&[199]), 0);
+        assert_eq!(count_increase(&[199, 200]), 1);
+        assert_eq!(count_increase(&[200, 199]), 0);
+    }
+
+    #[test]
+    fn count_three_measurement_window_increase_on_short_input() {
+
+        assert_eq!(count_three_measurement_window_increase(&[]), 0);
+        assert_eq!(count_three_measurement_window_increase(&[1]), 0);
+        assert_eq!(count_three_measurement_window_increase(&[1, 2]), 0);
+        assert_eq!(count_three_measurement_window_increase(&[1, 2, 3, 4]), 1);
+    }
+
+    #[test]
+    fn parse_example() {
+
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+
+        assert_eq!(parse_input("1\n2\n3").unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert_eq!(parse_input("").unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn parse_rejects_non_numbers() {
+
+        let error = parse_input("1\n abc\n3\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column, error.text.as_str()), (1, 2, 2, "abc"));
+    }
+
+    #[test]
+    fn generated_input_matches_naive() {
+
+        for seed in 0..5 {
+
+            let input = Day1::parse(&Day1::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            assert_eq!(Day1::part1(&input), naive_window_increases(&input, 1, 1));
+            assert_eq!(Day1::part2(&input), naive_window_increases(&input, 3, 1));
+        }
+    }
+
+    #[test]
+    fn window_increases_on_example() {
+
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(count_window_increases(&input, 1, 1), 7);
+        assert_eq!(count_window_increases(&input, 3, 1), 5);
+        assert_eq!(count_window_increases(&input, 2, 3), 5);
+    }
+
+    #[test]
+    fn window_increases_on_short_input() {
+
+        assert_eq!(count_window_increases(&[], 1, 1), 0);
+        assert_eq!(count_window_increases(&[1, 2, 3], 3, 1), 0);
+        assert_eq!(count_window_increases(&[1, 2, 3], 2, 1), 1);
+        assert_eq!(count_window_increases(&[1, 2, 3], 0, 1), 0);
+        assert_eq!(count_window_increases(&[1, 2, 3], 1, 0), 0);
+    }
+
+    /// Builds every window sum first, then compares them `stride` apart.
+    fn naive_window_increases(input: &[u32], window: usize, stride: usize) -> u64 {
+
+        let sums: Vec<u64> = input.windows(window).map(|readings| readings.iter().map(|reading| *reading as u64).sum()).collect();
+        return sums.iter().zip(sums.iter().skip(stride)).filter(|(earlier, later)| later > earlier).count() as u64;
+    }
+
+    proptest! {
+
+        #[test]
+        fn window_increases_match_naive(input in prop::collection::vec(0..1000u32, 0..100), window in 1..6usize, stride in 1..6usize) {
+
+            prop_assert_eq!(count_window_increases(&input, window, stride), naive_window_increases(&input, window, stride));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Increase counting over many readings per step.
+//!
+//! `std::simd` is still unstable, so this is portable chunked code written for the compiler to vectorise: each
+//! chunk compares `LANES` readings against the ones `window` later and adds the results into as many counters.
+
+/// Readings compared per step; 16 `u32` fill an AVX-512 register or two AVX2 ones.
+pub const LANES: usize = 16;
+
+// chunks counted into the `u32` lane counters before they are flushed, well below their overflow
+const CHUNKS_PER_BLOCK: usize = 1 << 16;
+
+
+/// Counts the readings `input[i]` for which `counts(input[i], input[i + window])` holds, one comparison at a time.
+pub fn count_pairs_scalar(input: &[u32], window: usize, counts: impl Fn(u32, u32) -> bool) -> u64 {
+
+    if window == 0 || input.len() <= window {
+
+        return 0;
+    }
+
+    return input.iter().zip(&input[window..]).filter(|(first, last)| counts(**first, **last)).count() as u64;
+}
+
+
+/// One comparison at a time, for short inputs and for what is left after the last full chunk.
+pub fn count_increases_scalar(input: &[u32], window: usize) -> u64 {
+
+    return count_pairs_scalar(input, window, |first, last| last > first);
+}
+
+
+/// Counts `input[i + window] > input[i]`, which is how often a window sum grows, `LANES` comparisons at a time.
+pub fn count_increases(input: &[u32], window: usize) -> u64 {
+
+    if window == 0 || input.len() <= window + LANES {
+
+        return count_increases_scalar(input, window);
+    }
+
+    let earlier = &input[..input.len() - window];
+    let later = &input[window..];
+    let mut count = 0;
+
+    for (earlier_block, later_block) in earlier.chunks(LANES * CHUNKS_PER_BLOCK).zip(later.chunks(LANES * CHUNKS_PER_BLOCK)) {
+
+        let mut lanes = [0u32; LANES];
+        let earlier_chunks = earlier_block.chunks_exact(LANES);
+        let later_chunks = later_block.chunks_exact(LANES);
+        let remainder = (earlier_chunks.remainder(), later_chunks.remainder());
+
+        for (earlier_chunk, later_chunk) in earlier_chunks.zip(later_chunks) {
+
+            for lane in 0..LANES {
+
+                lanes[lane] += (later_chunk[lane] > earlier_chunk[lane]) as u32;
+            }
+        }
+
+        count += lanes.iter().map(|lane| *lane as u64).sum::<u64>();
+        count += remainder.0.iter().zip(remainder.1).filter(|(first, last)| last > first).count() as u64;
+    }
+
+    return count;
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    #[test]
+    fn chunked_matches_scalar_around_chunk_boundaries() {
+
+        let input: Vec<u32> = (0..200u32).map(|idx| idx.wrapping_mul(2_654_435_761) % 1000).collect();
+
+        for len in [0, 1, LANES, LANES + 1, LANES + 2, 2 * LANES + 3, 200] {
+            for window in [0, 1, 3, LANES, 40] {
+
+                let input = &input[..len];
+                assert_eq!(count_increases(input, window), count_increases_scalar(input, window), "len {} window {}", len, window);
+            }
+        }
+    }
+
+    proptest! {
+
+        #[test]
+        fn chunked_matches_scalar(input in prop::collection::vec(0..50u32, 0..300), window in 1..40usize) {
+
+            prop_assert_eq!(count_increases(&input, window), count_increases_scalar(&input, window));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::simd;
+
+
+
+/// Window sizes `--stats` reports decreases for, the ones the two puzzle parts use.
+pub const STATS_WINDOWS: [usize; 2] = [1, 3];
+
+
+/// Shape of a sonar sweep beyond the two puzzle counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthStats {
+
+    pub readings: usize,
+    /// Readings in the longest strictly increasing run.
+    pub longest_increasing_run: usize,
+    /// `None` when the depth never goes up.
+    pub largest_increase: Option<u32>,
+    /// `None` when the depth never goes down.
+    pub largest_decrease: Option<u32>,
+    /// Consecutive readings that are equal.
+    pub plateaus: u64,
+    /// `(window, decreases)`, comparing each window sum with the one a reading earlier.
+    pub window_decreases: Vec<(usize, u64)>
+}
+
+
+/// Counts how often the sum of `window` consecutive readings is smaller than the one starting a reading earlier.
+pub fn count_window_decreases(input: &[u32], window: usize) -> u64 {
+
+    return simd::count_pairs_scalar(input, window, |first, last| last < first);
+}
+
+
+pub fn depth_stats(input: &[u32], windows: &[usize]) -> DepthStats {
+
+    let mut longest_increasing_run = input.len().min(1);
+    let mut current_run = longest_increasing_run;
+    let mut largest_increase = None;
+    let mut largest_decrease = None;
+    let mut plateaus = 0;
+
+    for (earlier, later) in input.iter().zip(input.get(1..).unwrap_or_default()) {
+
+        if later > earlier {
+
+            current_run += 1;
+            longest_increasing_run = longest_increasing_run.max(current_run);
+            largest_increase = largest_increase.max(Some(later - earlier));
+        }
+        else {
+
+            current_run = 1;
+
+            if later < earlier {
+
+                largest_decrease = largest_decrease.max(Some(earlier - later));
+            }
+            else {
+
+                plateaus += 1;
+            }
+        }
+    }
+
+    return DepthStats{
+        readings: input.len(),
+        longest_increasing_run,
+        largest_increase,
+        largest_decrease,
+        plateaus,
+        window_decreases: windows.iter().map(|window| (*window, count_window_decreases(input, *window))).collect()
+    };
+}
+
+
+impl std::fmt::Display for DepthStats {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        let or_none = |value: Option<u32>| value.map(|value| value.to_string()).unwrap_or_else(|| "none".to_owned());
+
+        writeln!(f, "readings: {}", self.readings)?;
+        writeln!(f, "longest increasing run: {} readings", self.longest_increasing_run)?;
+        writeln!(f, "largest increase: {}", or_none(self.largest_increase))?;
+        writeln!(f, "largest decrease: {}", or_none(self.largest_decrease))?;
+        write!(f, "equal consecutive readings: {}", self.plateaus)?;
+
+        for (window, decreases) in self.window_decreases.iter() {
+
+            write!(f, "\ndecreases with window {}: {}", window, decreases)?;
+        }
+
+        return Ok(());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::parse_input;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn stats_of_example() {
+
+        let stats = depth_stats(&parse_input(EXAMPLE).unwrap(), &STATS_WINDOWS);
+
+        assert_eq!(stats, DepthStats{
+            readings: 10,
+            longest_increasing_run: 4,
+            largest_increase: Some(33),
+            largest_decrease: Some(10),
+            plateaus: 0,
+            window_decreases: vec![(1, 2), (3, 1)]
+        });
+    }
+
+    #[test]
+    fn stats_of_short_and_flat_input() {
+
+        let empty = depth_stats(&[], &[1]);
+        assert_eq!((empty.longest_increasing_run, empty.largest_increase, empty.window_decreases), (0, None, vec![(1, 0)]));
+
+        let flat = depth_stats(&[5, 5, 5], &[1]);
+        assert_eq!((flat.longest_increasing_run, flat.largest_decrease, flat.plateaus), (1, None, 2));
+    }
+
+    #[test]
+    fn stats_display() {
+
+        let stats = depth_stats(&[3, 1, 1], &[1]);
+        assert_eq!(stats.to_string(), "\
+readings: 3
+longest increasing run: 1 readings
+largest increase: none
+largest decrease: 2
+equal consecutive readings: 1
+decreases with window 1: 1");
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use aoc_common::{Line, ParseError};
+
+use crate::parse_reading;
+
+
+
+#[derive(Debug)]
+pub enum SweepError {
+
+    Read(std::io::Error),
+    Parse(ParseError)
+}
+
+impl std::fmt::Display for SweepError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self {
+            SweepError::Read(error) => write!(f, "cannot read day 1 input: {}", error),
+            SweepError::Parse(error) => write!(f, "{}", error),
+        };
+    }
+}
+
+impl std::error::Error for SweepError {}
+
+
+/// Counts increases for several window sizes at once while readings arrive one by one.
+///
+/// Only the last `max(windows)` readings are kept, so memory does not grow with the input.
+pub struct SonarSweep {
+
+    windows: Vec<usize>,
+    counts: Vec<u64>,
+    longest: usize,
+    recent: VecDeque<u32>,
+    readings: u64
+}
+
+impl SonarSweep {
+
+    pub fn new(windows: &[usize]) -> Self {
+
+        let longest = windows.iter().copied().max().unwrap_or(0);
+
+        return Self{
+            windows: windows.to_vec(),
+            counts: vec![0; windows.len()],
+            longest,
+            recent: VecDeque::with_capacity(longest + 1),
+            readings: 0
+        };
+    }
+
+    pub fn push(&mut self, reading: u32) {
+
+        // the windows ending here and one reading earlier differ only in `reading` and the one `window` before it
+        for (window, count) in self.windows.iter().zip(self.counts.iter_mut()) {
+
+            if *window > 0 && *window <= self.recent.len() && reading > self.recent[self.recent.len() - window] {
+
+                *count += 1;
+            }
+        }
+
+        self.recent.push_back(reading);
+        if self.recent.len() > self.longest {
+
+            self.recent.pop_front();
+        }
+
+        self.readings += 1;
+    }
+
+    pub fn readings(&self) -> u64 {
+
+        return self.readings;
+    }
+
+    /// `(window, increases)` for every window size, in the order they were given.
+    pub fn counts(&self) -> Vec<(usize, u64)> {
+
+        return self.windows.iter().copied().zip(self.counts.iter().copied()).collect();
+    }
+}
+
+
+/// Reads readings line by line from `reader`, with the same rules as `parse_input`, and counts increases for
+/// every size in `windows` in a single pass.
+pub fn analyse(mut reader: impl BufRead, windows: &[usize]) -> Result<SonarSweep, SweepError> {
+
+    let mut sweep = SonarSweep::new(windows);
+    let mut buffer = String::new();
+    let mut number = 0;
+
+    loop {
+
+        buffer.clear();
+        if reader.read_line(&mut buffer).map_err(SweepError::Read)? == 0 {
+
+            break;
+        }
+
+        number += 1;
+        let text = buffer.strip_suffix('\n').map(|text| text.strip_suffix('\r').unwrap_or(text)).unwrap_or(&buffer);
+
+        sweep.push(parse_reading(Line{ day: 1, number, text }).map_err(SweepError::Parse)?);
+    }
+
+    return Ok(sweep);
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::{count_window_increases, parse_input};
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn analyse_example() {
+
+        let sweep = analyse(EXAMPLE.as_bytes(), &[1, 3]).unwrap();
+        assert_eq!(sweep.readings(), 10);
+        assert_eq!(sweep.counts(), vec![(1, 7), (3, 5)]);
+    }
+
+    #[test]
+    fn analyse_matches_count_window_increases() {
+
+        let raw_input = crate::generate_input(2000, &mut aoc_common::Rng::new(4));
+        let input = parse_input(&raw_input).unwrap();
+        let windows = [1, 2, 3, 10, 50];
+
+        let expected: Vec<(usize, u64)> = windows.iter().map(|window| (*window, count_window_increases(&input, *window, 1))).collect();
+        assert_eq!(analyse(raw_input.as_bytes(), &windows).unwrap().counts(), expected);
+    }
+
+    #[test]
+    fn analyse_follows_parse_rules() {
+
+        assert_eq!(analyse("1\r\n 2 \n3".as_bytes(), &[1]).unwrap().counts(), vec![(1, 2)]);
+        assert_eq!(analyse("".as_bytes(), &[1]).unwrap().readings(), 0);
+
+        let error = match analyse("1\n abc\n3\n".as_bytes(), &[1]) {
+            Err(SweepError::Parse(error)) => error,
+            other => panic!("expected a parse error, got {:?}", other.map(|sweep| sweep.counts())),
+        };
+        assert_eq!(error, parse_input("1\n abc\n3\n").unwrap_err());
+    }
+
+    #[test]
+    fn analyse_reports_read_errors() {
+
+        assert!(matches!(analyse(&[0xff, b'\n'][..], &[1]), Err(SweepError::Read(_))));
+    }
+}
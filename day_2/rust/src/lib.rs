@@ -0,0 +1,199 @@
+use aoc_common::{Line, ParseError, Rng, Solution};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+
+    Forward(u64),
+    Up(u64),
+    Down(u64)
+}
+
+pub fn parse_command(line: Line) -> Result<Command, ParseError> {
+
+    let text = line.text.trim();
+    let (name, number) = text.split_once(' ').ok_or_else(|| line.error(text, "expected `<command> <amount>`"))?;
+    let number = line.parse::<u64>(number)?;
+
+    let command = match name {
+
+        "forward" => Command::Forward(number),
+        "up" => Command::Up(number),
+        "down" => Command::Down(number),
+
+        _ => return Err(line.error(name, "unknown command, expected `forward`, `up` or `down`"))
+    };
+
+    return Ok(command);
+}
+
+pub fn parse_input(raw_input: &str) -> Result<Vec<Command>, ParseError> {
+
+    return aoc_common::lines(2, raw_input).map(parse_command).collect::<Result<_, _>>()
+}
+
+
+/// Commands that never take the submarine above the surface, so neither part underflows.
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    let mut depth = 0;
+    let mut output = String::with_capacity(size * 10);
+
+    for _ in 0..size {
+
+        let amount = rng.between(1, 9);
+
+        let name = match rng.below(3) {
+            0 if depth >= amount => {
+                depth -= amount;
+                "up"
+            },
+            1 => {
+                depth += amount;
+                "down"
+            },
+            _ => "forward",
+        };
+
+        output += &format!("{} {}\n", name, amount);
+    }
+
+    return output;
+}
+
+pub fn calculate_final_position_1(input: &[Command]) -> (u64, u64) {
+
+    let mut final_position = (0, 0);
+
+    for command in input.iter() {
+
+        match command {
+            Command::Forward(number) => final_position.0 += number,
+            Command::Up(number) => final_position.1 -= number,
+            Command::Down(number) => final_position.1 += number,
+        }
+    }
+
+    return final_position;
+}
+
+pub fn calculate_final_position_2(input: &[Command]) -> (u64, u64) {
+
+    let mut position = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for command in input.iter() {
+
+        match command {
+            Command::Forward(number) => {
+                position += number;
+                depth += aim * number;
+            },
+            Command::Up(number) => aim -= number,
+            Command::Down(number) => aim += number,
+        }
+    }
+
+    return (position, depth);
+}
+
+
+pub struct Day2;
+
+impl Solution for Day2 {
+
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        let final_position = calculate_final_position_1(input);
+        return final_position.0 * final_position.1;
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        let final_position = calculate_final_position_2(input);
+        return final_position.0 * final_position.1;
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_final_position_1(&input), (15, 10));
+        assert_eq!(Day2::part1(&input), 150);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_final_position_2(&input), (15, 60));
+        assert_eq!(Day2::part2(&input), 900);
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+
+        assert_eq!(parse_input("forward 5\nup 3").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert!(parse_input("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_command() {
+
+        let error = parse_input("forward 1\nsideways 3\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "sideways"));
+    }
+
+    #[test]
+    fn parse_rejects_bad_amount() {
+
+        let error = parse_input("forward x\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 9, "x"));
+
+        let error = parse_input("forward\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 1, "forward"));
+    }
+
+    #[test]
+    fn generated_input_parses() {
+
+        for seed in 0..5 {
+
+            let input = Day2::parse(&Day2::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            Day2::part1(&input);
+            Day2::part2(&input);
+        }
+    }
+}
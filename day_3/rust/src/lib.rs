@@ -0,0 +1,339 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+
+
+pub const LINE_SIZE: usize = 12;
+
+
+pub fn parse_input<const N: usize>(input : &str) -> Result<Vec<[u8; N]>, ParseError> {
+
+    let mut output = Vec::with_capacity(input.len() / (N + 1) + 100);
+
+    for line in aoc_common::lines(3, input).filter(|line| !line.text.trim().is_empty()) {
+
+        let text = line.text.trim();
+        if text.len() != N {
+
+            return Err(line.error(text, format!("expected {} bits, found {}", N, text.len())));
+        }
+
+        let mut line_content = [0; N];
+
+        for (idx, ch) in text.bytes().enumerate() {
+
+            line_content[idx] = match ch {
+                b'0' => 0,
+                b'1' => 1,
+                _ => return Err(line.error(&text[idx..idx + 1], "expected `0` or `1`")),
+            };
+        }
+
+        output.push(line_content);
+    }
+
+    if output.is_empty() {
+
+        return Err(aoc_common::empty_input(3, input));
+    }
+
+    return Ok(output);
+}
+
+pub fn generate_input<const N: usize>(size: usize, rng: &mut Rng) -> String {
+
+    let mut output = String::with_capacity(size * (N + 1));
+
+    for _ in 0..size {
+
+        output.extend((0..N).map(|_| if rng.below(2) == 1 { '1' } else { '0' }));
+        output.push('\n');
+    }
+
+    return output;
+}
+
+pub fn count_bits<const N: usize>(input: &[[u8; N]]) -> [u32; N] {
+
+    return input.iter().fold([0; N], 
+        |state, line| {
+            
+            let mut output = [0; N];
+            for (idx, (a, b)) in state.iter().zip(line.iter()).enumerate() {
+                output[idx] = *a + (*b as u32);
+            }
+    
+            return output;
+        }
+    )
+}
+
+pub fn bits_to_number<const N: usize>(input: &[u8; N]) -> u64 {
+
+    return input.iter().rev().enumerate().map(|(idx, bit)| u64::pow(2, idx as u32) * (*bit as u64)).sum();
+}
+
+pub fn calculate_part_1<const N: usize>(input: &[[u8; N]]) -> u64 {
+
+    let input_size = input.len();
+    let bit_count = count_bits(input);
+
+    let gamma_rate: u64 = bits_to_number(&bit_count.map(|number| (number * 2 >= input_size as u32) as u8));
+    let epsilon_rate = gamma_rate ^ (u64::MAX >> (64 - N));
+
+    return (gamma_rate as u128 * epsilon_rate as u128) as u64;
+}
+
+pub fn calculate_part_2<const N: usize>(input: &[[u8; N]]) -> u64 {
+
+    let filter_input = |filter_func: &mut dyn Fn(u32, usize) -> u8 | {
+        
+        let mut input = input.to_vec();
+        let mut bit_count = count_bits(&input);
+        let mut bit_idx = 0;
+
+        // duplicated lines can agree on every bit
+        while input.len() > 1 && bit_idx < N {
+
+            // when every line agrees on this bit there is no other value to keep, so it filters nothing
+            let ones = bit_count[bit_idx];
+            if ones != 0 && ones as usize != input.len() {
+
+                let value_to_filter = filter_func(ones, input.len());
+                input = filter_lines(input, value_to_filter, bit_idx);
+                bit_count = count_bits(&input);
+            }
+
+            bit_idx += 1;
+        }
+
+        return input[0];
+    };
+
+    let oxygen = bits_to_number(&filter_input(&mut |num, input_size| (num < ((input_size as u32) - num)) as u8));
+    let co2 = bits_to_number(&filter_input(&mut |num, input_size| (num >= ((input_size as u32) - num)) as u8));
+
+    return (oxygen as u128 * co2 as u128) as u64;
+}
+
+pub fn filter_lines<const N: usize>(mut input: Vec<[u8; N]>, value_to_filter: u8, position: usize) -> Vec<[u8; N]> {
+
+    let mut new_idx = 0;
+    let mut idx = 0;
+
+    while idx < input.len() {
+        
+        if input[idx][position] != value_to_filter {
+            
+            input[new_idx] = input[idx];
+            new_idx += 1;
+        }
+
+        idx += 1;
+    }
+    input.truncate(new_idx);
+
+    return input;
+}
+
+
+pub struct Day3;
+
+impl Solution for Day3 {
+
+    const DAY: u8 = 3;
+
+    type Input = Vec<[u8; LINE_SIZE]>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input::<LINE_SIZE>(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        return calculate_part_1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return calculate_part_2(input);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input::<LINE_SIZE>(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = parse_input::<5>(EXAMPLE).unwrap();
+        assert_eq!(calculate_part_1(&input), 198);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = parse_input::<5>(EXAMPLE).unwrap();
+        assert_eq!(calculate_part_2(&input), 230);
+    }
+
+    #[test]
+    fn part_1_odd_line_count() {
+
+        // gamma is 01 and epsilon 10
+        assert_eq!(calculate_part_1(&[[0, 0], [0, 1], [1, 1]]), 2);
+    }
+
+    #[test]
+    fn part_2_skips_bits_every_line_agrees_on() {
+
+        assert_eq!(calculate_part_2(&[[0, 1, 0], [0, 1, 1], [1, 1, 0], [1, 1, 1]]), 0b111 * 0b010);
+    }
+
+    #[test]
+    fn part_2_identical_lines() {
+
+        assert_eq!(calculate_part_2(&[[1, 0, 1], [0, 1, 1], [1, 0, 1]]), 0b101 * 0b011);
+        assert_eq!(calculate_part_2(&[[1, 0, 1], [1, 0, 1]]), 0b101 * 0b101);
+    }
+
+    #[test]
+    fn parse_example() {
+
+        let input = parse_input::<5>(EXAMPLE).unwrap();
+        assert_eq!(input.len(), 12);
+        assert_eq!(input[1], [1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn parse_ignores_trailing_newlines() {
+
+        assert_eq!(parse_input::<5>("00100\n11110").unwrap(), parse_input::<5>("00100\n11110\n\n\n").unwrap());
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert_eq!(parse_input::<5>("").unwrap_err().message, "empty input");
+        assert_eq!(parse_input::<5>("\n \n").unwrap_err().message, "empty input");
+    }
+
+    #[test]
+    fn parse_rejects_wrong_width() {
+
+        let error = parse_input::<5>("00100\n0110\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "0110"));
+    }
+
+    #[test]
+    fn parse_rejects_non_bits() {
+
+        let error = parse_input::<5>("00100\n01120\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, "2"));
+    }
+
+    #[test]
+    fn filter_lines_removes_matching_bits() {
+
+        let input = parse_input::<5>(EXAMPLE).unwrap();
+        let filtered = filter_lines(input, 0, 0);
+        assert_eq!(filtered.len(), 7);
+        assert!(filtered.iter().all(|line| line[0] == 1));
+    }
+
+    fn naive_value(bits: &[u8]) -> u64 {
+
+        return bits.iter().fold(0, |value, bit| value * 2 + *bit as u64);
+    }
+
+    /// Counts ones and zeros column by column with no bit tricks.
+    fn naive_part_1<const N: usize>(input: &[[u8; N]]) -> u64 {
+
+        let ones = |idx: usize| input.iter().filter(|line| line[idx] == 1).count();
+
+        let gamma: Vec<u8> = (0..N).map(|idx| (ones(idx) * 2 >= input.len()) as u8).collect();
+        let epsilon: Vec<u8> = gamma.iter().map(|bit| 1 - bit).collect();
+
+        return naive_value(&gamma) * naive_value(&epsilon);
+    }
+
+    /// Keeps the lines matching `keep(ones, zeros)` at each position, dropping the others every time.
+    fn naive_rating<const N: usize>(input: &[[u8; N]], keep: fn(usize, usize) -> u8) -> u64 {
+
+        let mut remaining = input.to_vec();
+
+        for idx in 0..N {
+
+            if remaining.len() == 1 {
+
+                break;
+            }
+
+            let ones = remaining.iter().filter(|line| line[idx] == 1).count();
+            if ones == 0 || ones == remaining.len() {
+
+                continue;
+            }
+
+            let bit = keep(ones, remaining.len() - ones);
+            remaining.retain(|line| line[idx] == bit);
+        }
+
+        return naive_value(&remaining[0]);
+    }
+
+    fn naive_part_2<const N: usize>(input: &[[u8; N]]) -> u64 {
+
+        let oxygen = naive_rating(input, |ones, zeros| (ones >= zeros) as u8);
+        let co2 = naive_rating(input, |ones, zeros| (ones < zeros) as u8);
+
+        return oxygen * co2;
+    }
+
+    /// 5 bit lines drawn from few enough values that they often repeat, so filtering can end on identical lines.
+    fn lines() -> impl Strategy<Value = Vec<[u8; 5]>> {
+
+        return prop::collection::vec(0..32u8, 1..=40)
+            .prop_map(|values| values.into_iter().map(|value| std::array::from_fn(|idx| (value >> (4 - idx)) & 1)).collect());
+    }
+
+    proptest! {
+
+        #[test]
+        fn part_1_matches_naive(input in lines()) {
+
+            prop_assert_eq!(calculate_part_1(&input), naive_part_1(&input));
+        }
+
+        #[test]
+        fn part_2_matches_naive(input in lines()) {
+
+            prop_assert_eq!(calculate_part_2(&input), naive_part_2(&input));
+        }
+    }
+
+    #[test]
+    fn generated_input_matches_naive() {
+
+        for seed in 0..5 {
+
+            let input = Day3::parse(&Day3::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            assert_eq!(Day3::part1(&input), naive_part_1(&input));
+            assert_eq!(Day3::part2(&input), naive_part_2(&input));
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use aoc_common::{Line, ParseError, Rng, Solution};
+use arrayvec::ArrayVec;
+
+
+
+pub const MATRICE_SIZE_LEN: usize = 5;
+
+
+
+#[derive(Debug, Clone)]
+pub struct Board {
+
+    pub columns: ArrayVec<ArrayVec<u16, MATRICE_SIZE_LEN>, MATRICE_SIZE_LEN>,
+    pub rows: ArrayVec<ArrayVec<u16, MATRICE_SIZE_LEN>, MATRICE_SIZE_LEN>
+}
+
+impl Board {
+
+    pub fn new(lines: &ArrayVec<ArrayVec<u16, MATRICE_SIZE_LEN>, MATRICE_SIZE_LEN>) -> Self {
+
+        let mut columns = (0..MATRICE_SIZE_LEN).map(|_| ArrayVec::new()).collect::<ArrayVec<_, MATRICE_SIZE_LEN>>();
+        let mut rows = (0..MATRICE_SIZE_LEN).map(|_| ArrayVec::new()).collect::<ArrayVec<_, MATRICE_SIZE_LEN>>();
+
+        for row in 0..MATRICE_SIZE_LEN {
+            for column in 0..MATRICE_SIZE_LEN {
+                
+                let value = lines[row][column];
+
+                let column_insert_pos = columns[row].binary_search(&value).unwrap_or_else(|pos| pos);
+                columns[row].insert(column_insert_pos, value);
+                
+                let row_insert_pos = rows[column].binary_search(&value).unwrap_or_else(|pos| pos);
+                rows[column].insert(row_insert_pos, value);
+            }
+        }
+
+        return Self{
+            columns,
+            rows
+        };
+    }
+}
+
+
+fn parse_row(line: Line) -> Result<ArrayVec<u16, MATRICE_SIZE_LEN>, ParseError> {
+
+    let mut row = ArrayVec::new();
+
+    for number in line.text.split_ascii_whitespace() {
+
+        if row.is_full() {
+
+            return Err(line.error(number, format!("board rows have {} numbers", MATRICE_SIZE_LEN)));
+        }
+
+        row.push(line.parse(number)?);
+    }
+
+    if !row.is_full() {
+
+        return Err(line.error(line.text.trim(), format!("board rows have {} numbers, found {}", MATRICE_SIZE_LEN, row.len())));
+    }
+
+    return Ok(row);
+}
+
+
+fn finish_board(rows: &ArrayVec<ArrayVec<u16, MATRICE_SIZE_LEN>, MATRICE_SIZE_LEN>, last_row: Line) -> Result<Board, ParseError> {
+
+    if !rows.is_full() {
+
+        return Err(last_row.error(last_row.text.trim(), format!("boards have {} rows, found {}", MATRICE_SIZE_LEN, rows.len())));
+    }
+
+    return Ok(Board::new(rows));
+}
+
+
+pub fn parse_input(input: &str) -> Result<(Vec<u16>, Vec<Board>), ParseError> {
+
+    let mut lines = aoc_common::lines(4, input);
+
+    let first_line = lines.next().ok_or_else(|| Line{ day: 4, number: 1, text: input }.error(input, "missing number sequence"))?;
+    let number_sequence = first_line.text.trim().split(',').map(|number| first_line.parse(number.trim())).collect::<Result<_, _>>()?;
+    let mut boards: Vec<Board> = vec![];
+
+    {
+        let mut current_board: ArrayVec<ArrayVec<u16, MATRICE_SIZE_LEN>, MATRICE_SIZE_LEN> = ArrayVec::new();
+        let mut last_row = first_line;
+
+        for line in lines {
+
+            if line.text.trim().is_empty() {
+
+                if !current_board.is_empty() {
+
+                    boards.push(finish_board(&current_board, last_row)?);
+                }
+
+                current_board = ArrayVec::new();
+            }
+            else {
+
+                if current_board.is_full() {
+
+                    return Err(line.error(line.text.trim(), format!("boards have {} rows", MATRICE_SIZE_LEN)));
+                }
+
+                current_board.push(parse_row(line)?);
+                last_row = line;
+            }
+        }
+
+        if !current_board.is_empty() {
+
+            boards.push(finish_board(&current_board, last_row)?);
+        }
+    }
+
+    return Ok((number_sequence, boards));
+}
+
+
+/// `size` boards of distinct numbers below 100 and a draw of all of them, so every board wins.
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    let mut numbers: Vec<u16> = (0..100).collect();
+
+    rng.shuffle(&mut numbers);
+    let mut output = numbers.iter().map(|number| number.to_string()).collect::<Vec<_>>().join(",") + "\n";
+
+    for _ in 0..size {
+
+        rng.shuffle(&mut numbers);
+        output.push('\n');
+
+        for row in numbers[..MATRICE_SIZE_LEN * MATRICE_SIZE_LEN].chunks(MATRICE_SIZE_LEN) {
+
+            output += &row.iter().map(|number| format!("{:>2}", number)).collect::<Vec<_>>().join(" ");
+            output.push('\n');
+        }
+    }
+
+    return output;
+}
+
+
+pub fn process_board(board: &mut Board, number: u16) -> Option<u64> {
+
+    for lines in [&mut board.rows, &mut board.columns].iter_mut() {
+
+        for line in lines.iter_mut() {
+
+            if let Ok(idx) = line.binary_search(&number) {
+
+                line.remove(idx);
+
+                if line.is_empty() {
+                    
+                    return Some(lines.iter().map(|line| line.iter().fold(0, |state, number| state + *number as u64)).sum());
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+
+pub fn calculate_part_1(input: &(Vec<u16>, Vec<Board>)) -> u64 {
+
+    let mut boards = input.1.clone();
+    for number in input.0.iter().copied() {
+
+        let result = boards.iter_mut().map(|board| process_board(board, number)).find(|result| result.is_some());
+
+        if let Some(Some(sum)) = result {
+
+            return sum * number as u64;
+        }
+    }
+
+    unreachable!();
+}
+
+
+pub fn calculate_part_2(input: &(Vec<u16>, Vec<Board>)) -> u64 {
+
+    let mut boards = input.1.clone();
+    for number in input.0.iter().copied() {
+
+        // several boards can win on the same number; the last one listed counts as the last to win
+        let mut last_score = None;
+        boards.retain_mut(|board| match process_board(board, number) {
+            Some(sum) => {
+                last_score = Some(sum * number as u64);
+                false
+            },
+            None => true,
+        });
+
+        if let (true, Some(score)) = (boards.is_empty(), last_score) {
+
+            return score;
+        }
+    }
+
+    unreachable!();
+}
+
+
+pub struct Day4;
+
+impl Solution for Day4 {
+
+    const DAY: u8 = 4;
+
+    type Input = (Vec<u16>, Vec<Board>);
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        return calculate_part_1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return calculate_part_2(input);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 4512);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), 1924);
+    }
+
+    #[test]
+    fn parse_example() {
+
+        let (numbers, boards) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(numbers.len(), 27);
+        assert_eq!(boards.len(), 3);
+    }
+
+    #[test]
+    fn parse_ignores_trailing_newlines() {
+
+        let (_, boards) = parse_input(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(boards.len(), 3);
+
+        let (_, boards) = parse_input(&format!("{}\n\n\n", EXAMPLE)).unwrap();
+        assert_eq!(boards.len(), 3);
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert_eq!(parse_input("").unwrap_err().message, "missing number sequence");
+    }
+
+    #[test]
+    fn parse_rejects_bad_numbers() {
+
+        let error = parse_input("1,2,x\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "x"));
+
+        let error = parse_input(&EXAMPLE.replace("21  9 14", "21  a 14")).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (5, 5, "a"));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_boards() {
+
+        let error = parse_input("1,2\n\n1 2 3 4\n").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (3, "1 2 3 4"));
+
+        let error = parse_input("1,2\n\n1 2 3 4 5\n6 7 8 9 10\n").unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.message, "boards have 5 rows, found 2");
+    }
+
+    #[test]
+    fn process_board_reports_unmarked_sum_on_win() {
+
+        let (_, boards) = parse_input(EXAMPLE).unwrap();
+        let mut board = boards[0].clone();
+
+        for number in [22, 13, 17, 11] {
+
+            assert_eq!(process_board(&mut board, number), None);
+        }
+
+        assert_eq!(process_board(&mut board, 0), Some(300 - 22 - 13 - 17 - 11));
+    }
+
+    #[test]
+    fn part_2_boards_winning_together() {
+
+        let first: Grid = std::array::from_fn(|row| std::array::from_fn(|column| (row * MATRICE_SIZE_LEN + column) as u16));
+        let mut second = first;
+        second[1..].iter_mut().flatten().for_each(|number| *number += 25);
+
+        assert_eq!(calculate_part_2(&(vec![0, 1, 2, 3, 4], boards(&[first, second]))), (30..50).sum::<u64>() * 4);
+    }
+
+    type Grid = [[u16; MATRICE_SIZE_LEN]; MATRICE_SIZE_LEN];
+
+    /// Plays every board as a plain grid of marks, returning `(turn, score)` for each board that wins.
+    fn naive_wins(numbers: &[u16], grids: &[Grid]) -> Vec<Option<(usize, u64)>> {
+
+        return grids.iter().map(|grid| {
+
+            let mut marked = [[false; MATRICE_SIZE_LEN]; MATRICE_SIZE_LEN];
+
+            for (turn, number) in numbers.iter().enumerate() {
+
+                for row in 0..MATRICE_SIZE_LEN {
+                    for column in 0..MATRICE_SIZE_LEN {
+
+                        if grid[row][column] == *number {
+
+                            marked[row][column] = true;
+                        }
+                    }
+                }
+
+                let full_row = (0..MATRICE_SIZE_LEN).any(|row| (0..MATRICE_SIZE_LEN).all(|column| marked[row][column]));
+                let full_column = (0..MATRICE_SIZE_LEN).any(|column| (0..MATRICE_SIZE_LEN).all(|row| marked[row][column]));
+
+                if full_row || full_column {
+
+                    let unmarked: u64 = (0..MATRICE_SIZE_LEN)
+                        .flat_map(|row| (0..MATRICE_SIZE_LEN).map(move |column| (row, column)))
+                        .filter(|(row, column)| !marked[*row][*column])
+                        .map(|(row, column)| grid[row][column] as u64)
+                        .sum();
+
+                    return Some((turn, unmarked * *number as u64));
+                }
+            }
+
+            return None;
+        }).collect();
+    }
+
+    /// The first board to win, boards listed earlier winning ties.
+    fn naive_part_1(numbers: &[u16], grids: &[Grid]) -> u64 {
+
+        return naive_wins(numbers, grids).iter().flatten().min_by_key(|(turn, _)| *turn).unwrap().1;
+    }
+
+    /// The last board to win, boards listed later winning ties.
+    fn naive_part_2(numbers: &[u16], grids: &[Grid]) -> u64 {
+
+        return naive_wins(numbers, grids).iter().flatten().max_by_key(|(turn, _)| *turn).unwrap().1;
+    }
+
+    /// Reads the boards straight off the text, without going through `parse_input` and `Board`.
+    fn grids(raw_input: &str) -> Vec<Grid> {
+
+        let numbers: Vec<u16> = raw_input.lines().skip(1).flat_map(|line| line.split_ascii_whitespace()).map(|number| number.parse().unwrap()).collect();
+        return numbers.chunks(MATRICE_SIZE_LEN * MATRICE_SIZE_LEN)
+            .map(|board| std::array::from_fn(|row| std::array::from_fn(|column| board[row * MATRICE_SIZE_LEN + column])))
+            .collect();
+    }
+
+    /// Boards of distinct numbers below 50 and a draw of every one of those numbers, so each board eventually wins.
+    fn game() -> impl Strategy<Value = (Vec<u16>, Vec<Grid>)> {
+
+        let grid = Just((0..50u16).collect::<Vec<_>>()).prop_shuffle()
+            .prop_map(|numbers| std::array::from_fn(|row| std::array::from_fn(|column| numbers[row * MATRICE_SIZE_LEN + column])));
+
+        return (Just((0..50u16).collect::<Vec<_>>()).prop_shuffle(), prop::collection::vec(grid, 1..6));
+    }
+
+    fn boards(grids: &[Grid]) -> Vec<Board> {
+
+        return grids.iter().map(|grid| Board::new(&grid.iter().map(|row| row.iter().copied().collect()).collect())).collect();
+    }
+
+    proptest! {
+
+        #[test]
+        fn part_1_matches_naive((numbers, grids) in game()) {
+
+            let expected = naive_part_1(&numbers, &grids);
+            prop_assert_eq!(calculate_part_1(&(numbers, boards(&grids))), expected);
+        }
+
+        #[test]
+        fn part_2_matches_naive((numbers, grids) in game()) {
+
+            let expected = naive_part_2(&numbers, &grids);
+            prop_assert_eq!(calculate_part_2(&(numbers, boards(&grids))), expected);
+        }
+    }
+
+    #[test]
+    fn generated_input_matches_naive() {
+
+        for seed in 0..5 {
+
+            let raw_input = Day4::generate(500, &mut aoc_common::Rng::new(seed));
+            let input = Day4::parse(&raw_input).unwrap();
+            let grids = grids(&raw_input);
+
+            assert_eq!(input.1.len(), 500);
+            assert_eq!(Day4::part1(&input), naive_part_1(&input.0, &grids));
+            assert_eq!(Day4::part2(&input), naive_part_2(&input.0, &grids));
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::ops::{Add, Sub};
+
+use aoc_common::{ParseError, Rng, Solution};
+use regex::Regex;
+
+
+
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Vec2 {
+
+    pub x: u32,
+    pub y: u32
+}
+
+impl Vec2 {
+
+    pub fn new(x: u32, y: u32) -> Self {
+
+        return Self{
+            x,
+            y
+        };
+    }
+}
+
+impl Add for Vec2 {
+    
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        
+        return Self{
+            x: self.x + rhs.x,
+            y: self.y + rhs.y
+        };
+    }
+}
+
+impl Sub for Vec2 {
+
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        
+        return Self{
+            x: self.x + rhs.x,
+            y: self.y + rhs.y
+        };
+    }
+}
+
+pub struct Map {
+
+    size: Vec2,
+    data: Vec<u8>
+}
+
+impl Map {
+
+    pub fn new(size: Vec2) -> Self {
+
+        let data = vec![0; size.x as usize * size.y as usize];
+
+        return Self{
+            size,
+            data
+        };
+    }
+
+    pub fn get(&mut self, pos: Vec2) -> &mut u8 {
+
+        return self.data.get_mut((self.size.x as usize* pos.y as usize) + pos.x as usize).unwrap();
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(Vec2, Vec2)>, ParseError> {
+
+    let match_vec_pair: Regex = Regex::new(r#"^(\d+),(\d+) -> (\d+),(\d+)$"#).unwrap();
+    let mut output = vec![];
+
+    for line in aoc_common::lines(5, input).filter(|line| !line.text.trim().is_empty()) {
+
+        let text = line.text.trim();
+        let captures = match_vec_pair.captures(text).ok_or_else(|| line.error(text, "expected `x1,y1 -> x2,y2`"))?;
+        let x1: u32 = line.parse(&text[captures.get(1).unwrap().range()])?;
+        let y1: u32 = line.parse(&text[captures.get(2).unwrap().range()])?;
+        let x2: u32 = line.parse(&text[captures.get(3).unwrap().range()])?;
+        let y2: u32 = line.parse(&text[captures.get(4).unwrap().range()])?;
+
+        // `calculate` walks one step at a time, so any other slope would step past the end point
+        if x1 != x2 && y1 != y2 && x1.abs_diff(x2) != y1.abs_diff(y2) {
+
+            return Err(line.error(text, "segment must be horizontal, vertical or diagonal at 45 degrees"));
+        }
+
+        output.push((Vec2::new(x1, y1), Vec2::new(x2, y2)));
+    }
+
+    return Ok(output);
+}
+
+
+/// Horizontal, vertical and diagonal segments on a 1000 by 1000 grid, some of them a single point.
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    let mut output = String::with_capacity(size * 20);
+
+    for _ in 0..size {
+
+        let start = rng.below(1000);
+        let end = rng.below(1000);
+        let other = rng.below(1000);
+
+        let (x1, y1, x2, y2) = match rng.below(3) {
+            0 => (start, other, end, other),
+            1 => (other, start, other, end),
+            _ => {
+                let length = start.abs_diff(end);
+                let low = rng.below(1000 - length);
+                if rng.below(2) == 0 { (start, low, end, low + length) } else { (start, low + length, end, low) }
+            },
+        };
+
+        output += &format!("{},{} -> {},{}\n", x1, y1, x2, y2);
+    }
+
+    return output;
+}
+
+
+pub fn is_straight(line: &(Vec2, Vec2)) -> bool {
+
+    return (line.0.x == line.1.x) || (line.0.y == line.1.y);
+}
+
+
+pub fn min_max(value: i64, min: i64, max: i64) -> i64 {
+
+    return i64::min(max, i64::max(min, value));
+}
+
+
+pub fn calculate(input: &[(Vec2, Vec2)]) -> u64 {
+
+    let insert_line = |map: &mut Map, line: &(Vec2, Vec2)| {
+
+        let mut current_position = line.0;
+        
+        let x_dir = min_max(line.1.x as i64 - line.0.x as i64, -1, 1);
+        let y_dir = min_max(line.1.y as i64 - line.0.y as i64, -1, 1);
+        
+        loop {
+        
+            *map.get(current_position) += 1;
+
+            if current_position == line.1 {
+
+                break;
+            }
+
+            current_position.x = (current_position.x as i64 + x_dir) as u32;
+            current_position.y = (current_position.y as i64 + y_dir) as u32;
+        }
+    };
+
+    let x_size = input.iter().flat_map(|lines| [lines.0.x, lines.1.x]).fold(0, |sum, x| if x > sum { x } else { sum }) + 1;
+    let y_size = input.iter().flat_map(|lines| [lines.0.y, lines.1.y]).fold(0, |sum, y| if y > sum { y } else { sum }) + 1;
+
+    let mut map = input.iter().fold(Map::new(Vec2::new(x_size, y_size)), |mut map, line| {insert_line(&mut map, line); map});
+
+    let mut total = 0;
+    for x in 0..x_size {
+        for y in 0..y_size {
+
+            if *map.get(Vec2::new(x, y)) >= 2 {
+
+                total += 1;
+            }
+        }
+    }
+
+    return total;
+}
+
+
+pub struct Day5;
+
+impl Solution for Day5 {
+
+    const DAY: u8 = 5;
+
+    type Input = Vec<(Vec2, Vec2)>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        let straight_lines: Vec<(Vec2, Vec2)> = input.iter().filter(|line| is_straight(line)).copied().collect();
+        return calculate(&straight_lines);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return calculate(input);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), 5);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), 12);
+    }
+
+    #[test]
+    fn parse_example() {
+
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(input.len(), 10);
+        assert_eq!(input[1], (Vec2::new(8, 0), Vec2::new(0, 8)));
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+
+        assert_eq!(parse_input(EXAMPLE.trim_end()).unwrap(), parse_input(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert!(parse_input("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+
+        let error = parse_input("0,9 -> 5,9\n8,0 => 0,8\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "8,0 => 0,8"));
+
+        let error = parse_input("0,9 -> 5,99999999999\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 10, "99999999999"));
+    }
+
+    #[test]
+    fn parse_rejects_segments_at_other_angles() {
+
+        let error = parse_input("0,9 -> 5,9\n  0,0 -> 1,5\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "0,0 -> 1,5"));
+        assert_eq!(error.message, "segment must be horizontal, vertical or diagonal at 45 degrees");
+
+        assert!(parse_input("5,5 -> 8,2\n3,3 -> 3,3\n").is_ok());
+    }
+
+    #[test]
+    fn single_point_segments_cover_their_point_once() {
+
+        assert_eq!(calculate(&parse_input("3,3 -> 3,3\n").unwrap()), 0);
+        assert_eq!(calculate(&parse_input("3,3 -> 3,3\n3,3 -> 3,3\n").unwrap()), 1);
+        assert_eq!(calculate(&parse_input("0,3 -> 5,3\n3,3 -> 3,3\n").unwrap()), 1);
+    }
+
+    #[test]
+    fn is_straight_only_accepts_horizontal_and_vertical_lines() {
+
+        assert!(is_straight(&(Vec2::new(0, 9), Vec2::new(5, 9))));
+        assert!(is_straight(&(Vec2::new(7, 0), Vec2::new(7, 4))));
+        assert!(!is_straight(&(Vec2::new(8, 0), Vec2::new(0, 8))));
+    }
+
+    #[test]
+    fn generated_input_parses() {
+
+        for seed in 0..5 {
+
+            let input = Day5::parse(&Day5::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            Day5::part1(&input);
+            Day5::part2(&input);
+        }
+    }
+}
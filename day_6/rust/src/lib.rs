@@ -0,0 +1,185 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+
+
+pub fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+
+    return aoc_common::comma_separated_with(6, input, |line, token| {
+
+        let timer = line.parse(token)?;
+        if timer > 8 {
+
+            return Err(line.error(token, "timer must be between 0 and 8"));
+        }
+
+        return Ok(timer);
+    });
+}
+
+
+/// Timers between 1 and 5, the range the real inputs use.
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    return (0..size).map(|_| rng.between(1, 5).to_string()).collect::<Vec<_>>().join(",") + "\n";
+}
+
+
+pub fn calculate(init_fishes: &[u8], day_count: u64) -> u64 {
+
+    let mut fishes = [0; 9];
+
+    for fish in init_fishes.iter() {
+
+        fishes[(8 - *fish) as usize] += 1;
+    }
+
+    for _ in 0..day_count {
+
+        fishes.rotate_right(1);
+        fishes[2] += fishes[0];
+    }
+
+    return fishes.iter().sum();
+}
+
+
+pub struct Day6;
+
+impl Solution for Day6 {
+
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        return calculate(input, 80);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return calculate(input, 256);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "3,4,3,1,2\n";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate(&input, 18), 26);
+        assert_eq!(Day6::part1(&input), 5934);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), 26984457539);
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+
+        assert_eq!(parse_input("3,4,3,1,2").unwrap(), vec![3, 4, 3, 1, 2]);
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![3, 4, 3, 1, 2]);
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert!(parse_input("").is_err());
+        assert!(parse_input("\n").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_bad_timers() {
+
+        let error = parse_input("3,4,-1\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column, error.text.as_str()), (6, 1, 5, "-1"));
+    }
+
+    #[test]
+    fn parse_rejects_timers_above_eight() {
+
+        let error = parse_input("3,4,9,1\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "9"));
+        assert_eq!(error.message, "timer must be between 0 and 8");
+        assert!(parse_input("0,8\n").is_ok());
+    }
+
+    #[test]
+    fn calculate_without_days_counts_initial_fishes() {
+
+        assert_eq!(calculate(&[3, 4, 3, 1, 2], 0), 5);
+    }
+
+    /// Simulates every fish one by one, exactly as the puzzle describes it.
+    fn naive_calculate(init_fishes: &[u8], day_count: u64) -> u64 {
+
+        let mut fishes = init_fishes.to_vec();
+
+        for _ in 0..day_count {
+
+            let mut newborns = 0;
+            for fish in fishes.iter_mut() {
+
+                if *fish == 0 {
+
+                    *fish = 6;
+                    newborns += 1;
+                }
+                else {
+
+                    *fish -= 1;
+                }
+            }
+
+            fishes.extend(std::iter::repeat_n(8, newborns));
+        }
+
+        return fishes.len() as u64;
+    }
+
+    proptest! {
+
+        #[test]
+        fn calculate_matches_naive(init_fishes in prop::collection::vec(0..=8u8, 1..20), day_count in 0..60u64) {
+
+            prop_assert_eq!(calculate(&init_fishes, day_count), naive_calculate(&init_fishes, day_count));
+        }
+    }
+
+    #[test]
+    fn generated_input_matches_naive() {
+
+        for seed in 0..5 {
+
+            let input = Day6::parse(&Day6::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            assert_eq!(Day6::part1(&input), naive_calculate(&input, 80));
+            // by day 256 the school has far too many fish to simulate one by one
+            Day6::part2(&input);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+
+
+pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+
+    return aoc_common::comma_separated(7, input);
+}
+
+
+pub fn generate_input(size: usize, rng: &mut Rng) -> String {
+
+    return (0..size).map(|_| rng.below(2000).to_string()).collect::<Vec<_>>().join(",") + "\n";
+}
+
+
+pub fn part_1(input: &[u32]) -> u64 {
+
+    let mut input = input.to_vec();
+    input.sort();
+
+    // any point between the two middle values minimises the sum of distances
+    let destination = input[input.len() / 2];
+
+    return input.iter().fold(0, |sum ,n| sum + (destination as i64 - *n as i64).unsigned_abs());
+}
+
+
+pub fn part_2(input: &[u32]) -> u64 {
+
+    let avg = (input.iter().fold(0, |sum, num| sum + *num as u64) / input.len() as u64) as u32;
+
+    let costs: Vec<u64> = [avg, avg + 1].iter().map(|avg| {
+        
+        input.iter().fold(0, |sum, num| {
+
+            let distance = (*avg as i64 - *num as i64).unsigned_abs();
+            let triangle = (distance * (distance + 1)) / 2;
+            return sum + triangle;
+        })
+    }).collect();
+
+    return *costs.iter().min().unwrap();
+}
+
+
+pub struct Day7;
+
+impl Solution for Day7 {
+
+    const DAY: u8 = 7;
+
+    type Input = Vec<u32>;
+    type Answer = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+
+        return parse_input(raw_input);
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+
+        return part_1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+
+        return part_2(input);
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+
+        return generate_input(size, rng);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14\n";
+
+    #[test]
+    fn part_1_example() {
+
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 37);
+    }
+
+    #[test]
+    fn part_2_example() {
+
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 168);
+    }
+
+    #[test]
+    fn part_1_odd_length() {
+
+        assert_eq!(part_1(&[1, 2, 10]), 9);
+    }
+
+    #[test]
+    fn parse_without_trailing_newline() {
+
+        assert_eq!(parse_input(EXAMPLE.trim_end()).unwrap(), parse_input(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn parse_empty_input() {
+
+        assert!(parse_input("").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_bad_positions() {
+
+        let error = parse_input("16,1,two\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column, error.text.as_str()), (7, 1, 6, "two"));
+    }
+
+    /// Tries every position between the two outermost crabs.
+    fn naive_cheapest(input: &[u32], cost: impl Fn(u64) -> u64) -> u64 {
+
+        let (min, max) = (*input.iter().min().unwrap(), *input.iter().max().unwrap());
+
+        return (min..=max).map(|position| input.iter().map(|n| cost((position as i64 - *n as i64).unsigned_abs())).sum()).min().unwrap();
+    }
+
+    proptest! {
+
+        #[test]
+        fn part_1_matches_naive(input in prop::collection::vec(0..1000u32, 1..100)) {
+
+            prop_assert_eq!(part_1(&input), naive_cheapest(&input, |distance| distance));
+        }
+
+        #[test]
+        fn part_2_matches_naive(input in prop::collection::vec(0..1000u32, 1..100)) {
+
+            prop_assert_eq!(part_2(&input), naive_cheapest(&input, |distance| distance * (distance + 1) / 2));
+        }
+    }
+
+    #[test]
+    fn generated_input_matches_naive() {
+
+        for seed in 0..5 {
+
+            let input = Day7::parse(&Day7::generate(500, &mut aoc_common::Rng::new(seed))).unwrap();
+            assert_eq!(input.len(), 500);
+            assert_eq!(Day7::part1(&input), naive_cheapest(&input, |distance| distance));
+            assert_eq!(Day7::part2(&input), naive_cheapest(&input, |distance| distance * (distance + 1) / 2));
+        }
+    }
+}